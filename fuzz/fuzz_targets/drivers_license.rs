@@ -0,0 +1,14 @@
+#![no_main]
+
+use aamva_rs::documents::{DriversLicense, Reader};
+use aamva_rs::options::ParserOptions;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = DriversLicense::from_bytes(bytes, &ParserOptions::default());
+
+    if let Ok(file) = std::str::from_utf8(bytes) {
+        let _ = DriversLicense::new(file.to_string(), &ParserOptions::default());
+        let _ = DriversLicense::read(file.to_string(), &ParserOptions::lenient());
+    }
+});
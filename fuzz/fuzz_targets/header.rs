@@ -0,0 +1,8 @@
+#![no_main]
+
+use aamva_rs::header::Header;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|header: String| {
+    let _ = Header::from(header);
+});
@@ -0,0 +1,100 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::Error;
+
+/// Age in whole years on the given day, `None` when the day is before the date of birth.
+pub fn age_on(date_of_birth: NaiveDate, date: NaiveDate) -> Option<u32> {
+    if date < date_of_birth {
+        return None;
+    }
+
+    let years = (date.year() - date_of_birth.year()) as u32;
+
+    // Someone born on February 29 has not had their birthday on February 28 of a common year.
+    match (date.month(), date.day()) < (date_of_birth.month(), date_of_birth.day()) {
+        true => Some(years - 1),
+        false => Some(years)
+    }
+}
+
+/// The day someone turns the given age. Birthdays on February 29 fall on March 1 in common years, the day they are
+/// legally considered to have aged in most jurisdictions.
+pub fn becomes_of_age(date_of_birth: NaiveDate, years: u32) -> Option<NaiveDate> {
+    let year = date_of_birth.year().checked_add(i32::try_from(years).ok()?)?;
+
+    date_of_birth.with_year(year).or_else(|| NaiveDate::from_ymd_opt(year, 3, 1))
+}
+
+/// Whether someone is at least the given age on the given day.
+pub fn is_at_least(date_of_birth: NaiveDate, years: u32, date: NaiveDate) -> bool {
+    becomes_of_age(date_of_birth, years).is_some_and(|birthday| birthday <= date)
+}
+
+/// Compares the under 18, 19 and 21 dates (DDH, DDI and DDJ) given as `(element_id, years, date)` with the ones the
+/// date of birth implies. For a February 29 birth in a common year both February 28 and March 1 are accepted, as
+/// jurisdictions differ on which day the birthday falls.
+pub fn threshold_mismatches(date_of_birth: Option<NaiveDate>, thresholds: &[(&str, u32, Option<NaiveDate>)]) -> Vec<Error> {
+    let Some(date_of_birth) = date_of_birth else {
+        return Vec::new();
+    };
+
+    thresholds.iter()
+        .filter_map(|(element_id, years, found)| {
+            let found = (*found)?;
+            let expected = becomes_of_age(date_of_birth, *years)?;
+            let leap_day_eve = date_of_birth.with_year(expected.year()).is_none()
+                .then(|| expected.pred_opt())
+                .flatten();
+
+            (found != expected && Some(found) != leap_day_eve).then(|| Error::AgeThresholdMismatch { element_id: element_id.to_string(), found, expected })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::age::{age_on, becomes_of_age, is_at_least, threshold_mismatches};
+    use crate::error::Error;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_age_on() {
+        assert_eq!(age_on(date(1977, 8, 31), date(2024, 8, 30)), Some(46));
+        assert_eq!(age_on(date(1977, 8, 31), date(2024, 8, 31)), Some(47));
+        assert_eq!(age_on(date(1977, 8, 31), date(1977, 8, 31)), Some(0));
+        assert_eq!(age_on(date(1977, 8, 31), date(1977, 8, 30)), None);
+    }
+
+    #[test]
+    fn test_leap_day_birthdays() {
+        let date_of_birth = date(2004, 2, 29);
+
+        assert_eq!(becomes_of_age(date_of_birth, 21), Some(date(2025, 3, 1)));
+        assert_eq!(becomes_of_age(date_of_birth, 20), Some(date(2024, 2, 29)));
+        assert_eq!(age_on(date_of_birth, date(2025, 2, 28)), Some(20));
+        assert_eq!(age_on(date_of_birth, date(2025, 3, 1)), Some(21));
+        assert!(!is_at_least(date_of_birth, 21, date(2025, 2, 28)));
+        assert!(is_at_least(date_of_birth, 21, date(2025, 3, 1)));
+    }
+
+    #[test]
+    fn test_threshold_mismatches() {
+        let date_of_birth = Some(date(2004, 2, 29));
+
+        assert!(threshold_mismatches(date_of_birth, &[("DDJ", 21, Some(date(2025, 3, 1)))]).is_empty());
+        assert!(threshold_mismatches(date_of_birth, &[("DDJ", 21, Some(date(2025, 2, 28)))]).is_empty());
+        assert!(threshold_mismatches(None, &[("DDJ", 21, Some(date(2025, 2, 27)))]).is_empty());
+
+        let mismatches = threshold_mismatches(date_of_birth, &[("DDH", 18, None), ("DDJ", 21, Some(date(2025, 2, 27)))]);
+        assert!(matches!(&mismatches[..], [Error::AgeThresholdMismatch { element_id, .. }] if element_id == "DDJ"));
+
+        // February 28 is only accepted for a leap day birth.
+        let mismatches = threshold_mismatches(Some(date(2004, 3, 1)), &[("DDJ", 21, Some(date(2025, 2, 28)))]);
+        assert_eq!(mismatches.len(), 1);
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Supplies the current time, so that checks which depend on "now" can be tested with a fixed date.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+
+    fn today(&self) -> NaiveDate {
+        self.now().date_naive()
+    }
+}
+
+/// The system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that is stopped at a given instant.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl FixedClock {
+
+    /// A clock stopped at noon UTC on the given day.
+    pub fn on(date: NaiveDate) -> FixedClock {
+        FixedClock(date.and_hms_opt(12, 0, 0).unwrap_or_default().and_utc())
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
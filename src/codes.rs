@@ -0,0 +1,366 @@
+use std::fmt;
+
+/// An AAMVA code table, such as the eye colours of DAY.
+pub trait Code: Sized {
+
+    /// Looks a code up in the table, also accepting the spelled-out values some jurisdictions still encode.
+    fn from_code(code: &str) -> Option<Self>;
+
+    /// Keeps a code that is not in the table.
+    fn other(code: &str) -> Self;
+
+    /// The code as the standard writes it.
+    fn code(&self) -> &str;
+
+    /// A human readable label for the code.
+    fn label(&self) -> &str;
+}
+
+// Finds `code` in a table of (value, code, label, legacy spellings) rows.
+fn lookup<T: Clone>(table: &[(T, &str, &str, &[&str])], code: &str) -> Option<T> {
+    let code = code.trim().to_ascii_uppercase();
+
+    table.iter()
+        .find(|(_, standard, _, legacy)| *standard == code || legacy.contains(&code.as_str()))
+        .map(|(value, ..)| value.clone())
+}
+
+fn row<'a, T: PartialEq>(table: &'a [(T, &'a str, &'a str, &'a [&'a str])], value: &T) -> Option<&'a (T, &'a str, &'a str, &'a [&'a str])> {
+    table.iter().find(|(row, ..)| row == value)
+}
+
+// Implements `Code` and `Display` for an enum with an `Other` variant from its table of rows.
+macro_rules! impl_code {
+    ($code:ident, $table:ident) => {
+        impl Code for $code {
+            fn from_code(code: &str) -> Option<$code> {
+                lookup($table, code)
+            }
+
+            fn other(code: &str) -> $code {
+                $code::Other(code.to_string())
+            }
+
+            fn code(&self) -> &str {
+                match self {
+                    $code::Other(code) => code,
+                    value => row($table, value).map_or("", |(_, code, ..)| code)
+                }
+            }
+
+            fn label(&self) -> &str {
+                match self {
+                    $code::Other(code) => code,
+                    value => row($table, value).map_or("", |(_, _, label, _)| label)
+                }
+            }
+        }
+
+        impl fmt::Display for $code {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.label())
+            }
+        }
+    };
+}
+
+/// Sex of the cardholder (DBC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sex {
+    Male,
+    Female,
+    NotSpecified,
+    Other(String)
+}
+
+const SEXES: &[(Sex, &str, &str, &[&str])] = &[
+    (Sex::Male,         "1", "Male",          &["M", "MALE"]),
+    (Sex::Female,       "2", "Female",        &["F", "FEMALE"]),
+    (Sex::NotSpecified, "9", "Not specified", &["X", "U"])
+];
+
+impl_code!(Sex, SEXES);
+
+/// Eye colour of the cardholder (DAY).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EyeColor {
+    Black,
+    Blue,
+    Brown,
+    Gray,
+    Green,
+    Hazel,
+    Maroon,
+    Pink,
+    Dichromatic,
+    Unknown,
+    Other(String)
+}
+
+const EYE_COLORS: &[(EyeColor, &str, &str, &[&str])] = &[
+    (EyeColor::Black,       "BLK", "Black",       &["BLACK"]),
+    (EyeColor::Blue,        "BLU", "Blue",        &["BLUE"]),
+    (EyeColor::Brown,       "BRO", "Brown",       &["BROWN", "BRN"]),
+    (EyeColor::Gray,        "GRY", "Gray",        &["GRAY", "GREY"]),
+    (EyeColor::Green,       "GRN", "Green",       &["GREEN"]),
+    (EyeColor::Hazel,       "HAZ", "Hazel",       &["HAZEL"]),
+    (EyeColor::Maroon,      "MAR", "Maroon",      &["MAROON"]),
+    (EyeColor::Pink,        "PNK", "Pink",        &["PINK"]),
+    (EyeColor::Dichromatic, "DIC", "Dichromatic", &["DICHROMATIC"]),
+    (EyeColor::Unknown,     "UNK", "Unknown",     &["UNKNOWN"])
+];
+
+impl_code!(EyeColor, EYE_COLORS);
+
+/// Hair colour of the cardholder (DAZ).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HairColor {
+    Bald,
+    Black,
+    Blond,
+    Brown,
+    Gray,
+    RedAuburn,
+    Sandy,
+    White,
+    Unknown,
+    Other(String)
+}
+
+const HAIR_COLORS: &[(HairColor, &str, &str, &[&str])] = &[
+    (HairColor::Bald,      "BAL", "Bald",        &["BALD"]),
+    (HairColor::Black,     "BLK", "Black",       &["BLACK"]),
+    (HairColor::Blond,     "BLN", "Blond",       &["BLOND", "BLONDE"]),
+    (HairColor::Brown,     "BRO", "Brown",       &["BROWN", "BRN"]),
+    (HairColor::Gray,      "GRY", "Gray",        &["GRAY", "GREY"]),
+    (HairColor::RedAuburn, "RED", "Red/Auburn",  &["AUBURN"]),
+    (HairColor::Sandy,     "SDY", "Sandy",       &["SANDY"]),
+    (HairColor::White,     "WHI", "White",       &["WHITE"]),
+    (HairColor::Unknown,   "UNK", "Unknown",     &["UNKNOWN"])
+];
+
+impl_code!(HairColor, HAIR_COLORS);
+
+/// Race or ethnicity of the cardholder (DCL).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RaceEthnicity {
+    AlaskanOrAmericanIndian,
+    AsianOrPacificIslander,
+    Black,
+    HispanicOrigin,
+    NonHispanic,
+    Unknown,
+    White,
+    Other(String)
+}
+
+const RACES: &[(RaceEthnicity, &str, &str, &[&str])] = &[
+    (RaceEthnicity::AlaskanOrAmericanIndian, "AI", "Alaskan or American Indian", &["AMERICAN INDIAN", "ALASKAN"]),
+    (RaceEthnicity::AsianOrPacificIslander,  "AP", "Asian or Pacific Islander",  &["ASIAN", "PACIFIC ISLANDER"]),
+    (RaceEthnicity::Black,                   "BK", "Black",                      &["BLACK"]),
+    (RaceEthnicity::HispanicOrigin,          "H",  "Hispanic Origin",            &["HISPANIC"]),
+    (RaceEthnicity::NonHispanic,             "O",  "Non-hispanic",               &["NON-HISPANIC"]),
+    (RaceEthnicity::Unknown,                 "U",  "Unknown",                    &["UNKNOWN"]),
+    (RaceEthnicity::White,                   "W",  "White",                      &["WHITE"])
+];
+
+impl_code!(RaceEthnicity, RACES);
+
+/// Class of vehicle the licence allows. The standard classes are those of DCM, the jurisdiction classes of DCA are
+/// always `Other`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VehicleClass {
+    A,
+    B,
+    C,
+    M,
+    Other(String)
+}
+
+const VEHICLE_CLASSES: &[(VehicleClass, &str, &str, &[&str])] = &[
+    (VehicleClass::A, "A", "Combination vehicles of 26,001 lb or more, towing more than 10,000 lb", &[]),
+    (VehicleClass::B, "B", "Single vehicles of 26,001 lb or more",                                   &[]),
+    (VehicleClass::C, "C", "Vehicles carrying 16 or more passengers or hazardous materials",         &[]),
+    (VehicleClass::M, "M", "Motorcycles",                                                           &[])
+];
+
+impl_code!(VehicleClass, VEHICLE_CLASSES);
+
+/// Endorsement on the licence. The standard endorsements are those of DCN, the jurisdiction endorsements of DCD are
+/// always `Other`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Endorsement {
+    HazardousMaterials,
+    TankVehicle,
+    Passenger,
+    SchoolBus,
+    DoubleTripleTrailers,
+    TankVehicleWithHazardousMaterials,
+    Other(String)
+}
+
+const ENDORSEMENTS: &[(Endorsement, &str, &str, &[&str])] = &[
+    (Endorsement::HazardousMaterials,                "H", "Hazardous materials",                       &[]),
+    (Endorsement::TankVehicle,                       "N", "Tank vehicle",                              &[]),
+    (Endorsement::Passenger,                         "P", "Passengers",                                &[]),
+    (Endorsement::SchoolBus,                         "S", "School bus",                                &[]),
+    (Endorsement::DoubleTripleTrailers,              "T", "Double and triple trailers",                &[]),
+    (Endorsement::TankVehicleWithHazardousMaterials, "X", "Tank vehicle carrying hazardous materials", &[])
+];
+
+impl_code!(Endorsement, ENDORSEMENTS);
+
+/// Restriction on the licence. The standard restrictions are those of DCO, the jurisdiction restrictions of DCB are
+/// always `Other`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Restriction {
+    CorrectiveLenses,
+    MechanicalAid,
+    ProstheticAid,
+    AutomaticTransmission,
+    OutsideMirror,
+    DaylightOnly,
+    EmploymentOnly,
+    LimitedOther,
+    OtherRestriction,
+    IntrastateOnly,
+    NoAirBrakes,
+    ExceptClassABus,
+    ExceptClassAAndBBus,
+    ExceptTractorTrailer,
+    NoPassengers,
+    MedicalVariance,
+    FarmWaiver,
+    NoCargoInTankVehicle,
+    NoFullAirBrakes,
+    Other(String)
+}
+
+const RESTRICTIONS: &[(Restriction, &str, &str, &[&str])] = &[
+    (Restriction::CorrectiveLenses,      "B", "Corrective lenses required",                 &[]),
+    (Restriction::MechanicalAid,         "C", "Mechanical aid",                             &[]),
+    (Restriction::ProstheticAid,         "D", "Prosthetic aid",                             &[]),
+    (Restriction::AutomaticTransmission, "E", "Automatic transmission only",                &[]),
+    (Restriction::OutsideMirror,         "F", "Outside mirror required",                    &[]),
+    (Restriction::DaylightOnly,          "G", "Daylight driving only",                      &[]),
+    (Restriction::EmploymentOnly,        "H", "Limited to employment",                      &[]),
+    (Restriction::LimitedOther,          "I", "Limited, other",                             &[]),
+    (Restriction::OtherRestriction,      "J", "Other",                                      &[]),
+    (Restriction::IntrastateOnly,        "K", "Commercial driving within the state only",   &[]),
+    (Restriction::NoAirBrakes,           "L", "Vehicles without air brakes only",           &[]),
+    (Restriction::ExceptClassABus,       "M", "Except class A buses",                       &[]),
+    (Restriction::ExceptClassAAndBBus,   "N", "Except class A and class B buses",           &[]),
+    (Restriction::ExceptTractorTrailer,  "O", "Except tractor-trailers",                    &[]),
+    (Restriction::NoPassengers,          "P", "No passengers in commercial buses",          &[]),
+    (Restriction::MedicalVariance,       "V", "Medical variance",                           &[]),
+    (Restriction::FarmWaiver,            "W", "Farm waiver",                                &[]),
+    (Restriction::NoCargoInTankVehicle,  "X", "No cargo in commercial tank vehicles",       &[]),
+    (Restriction::NoFullAirBrakes,       "Z", "No full air brake commercial vehicles",      &[])
+];
+
+impl_code!(Restriction, RESTRICTIONS);
+
+/// The codes of a class, endorsement or restriction element, with the jurisdiction's description of them (DCP, DCQ
+/// or DCR) when the card carries one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeList<T> {
+    pub codes: Vec<T>,
+    pub description: Option<String>
+}
+
+impl<T: Code> CodeList<T> {
+
+    /// Splits a value of the standard codes such as "BJ", "B,J" or "B J" into its codes, "NONE" is an empty list.
+    pub fn parse(value: &str, description: Option<String>) -> CodeList<T> {
+        Self::split(value, description, true)
+    }
+
+    /// Splits a value of jurisdiction codes such as "C1", "A,M" or "A M" on its separators only, as jurisdictions
+    /// use codes longer than one character. The codes are all kept as `Other`, a jurisdiction class "C" is not the
+    /// standard class C, and are described by DCP, DCQ or DCR when the card holds them.
+    pub fn parse_jurisdiction(value: &str, description: Option<String>) -> CodeList<T> {
+        Self::split(value, description, false)
+    }
+
+    fn split(value: &str, description: Option<String>, standard: bool) -> CodeList<T> {
+        let value = value.trim();
+
+        let code = |code: &str| match standard {
+            true => T::from_code(code).unwrap_or_else(|| T::other(code)),
+            false => T::other(code)
+        };
+
+        let codes = if value.is_empty() || value.eq_ignore_ascii_case("NONE") {
+            Vec::new()
+        } else if value.contains([',', ' ']) || !standard {
+            value.split([',', ' ']).filter(|value| !value.is_empty()).map(code).collect()
+        } else {
+            value.chars().map(|value| code(&value.to_string())).collect()
+        };
+
+        CodeList { codes, description }
+    }
+
+    pub fn contains(&self, code: &T) -> bool where T: PartialEq {
+        self.codes.contains(code)
+    }
+
+    /// The jurisdiction's description when present, otherwise the labels of the codes.
+    pub fn describe(&self) -> String {
+        match &self.description {
+            Some(description) => description.clone(),
+            None => self.codes.iter().map(T::label).collect::<Vec<&str>>().join(", ")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codes::{Code, CodeList, Endorsement, EyeColor, HairColor, RaceEthnicity, Restriction, Sex, VehicleClass};
+
+    #[test]
+    fn test_codes() {
+        assert_eq!(Sex::from_code("1"), Some(Sex::Male));
+        assert_eq!(EyeColor::from_code("HAZ"), Some(EyeColor::Hazel));
+        assert_eq!(HairColor::from_code("SDY"), Some(HairColor::Sandy));
+        assert_eq!(RaceEthnicity::from_code("AP"), Some(RaceEthnicity::AsianOrPacificIslander));
+        assert_eq!(EyeColor::from_code("XYZ"), None);
+
+        assert_eq!(EyeColor::Dichromatic.code(), "DIC");
+        assert_eq!(HairColor::RedAuburn.to_string(), "Red/Auburn");
+        assert_eq!(Sex::other("7").code(), "7");
+    }
+
+    #[test]
+    fn test_legacy_spellings() {
+        assert_eq!(Sex::from_code("F"), Some(Sex::Female));
+        assert_eq!(EyeColor::from_code("brown"), Some(EyeColor::Brown));
+        assert_eq!(HairColor::from_code("BLONDE"), Some(HairColor::Blond));
+        assert_eq!(RaceEthnicity::from_code("WHITE"), Some(RaceEthnicity::White));
+    }
+
+    #[test]
+    fn test_code_lists() {
+        let restrictions: CodeList<Restriction> = CodeList::parse("BJ", None);
+        assert_eq!(restrictions.codes, vec![Restriction::CorrectiveLenses, Restriction::OtherRestriction]);
+        assert_eq!(restrictions.describe(), "Corrective lenses required, Other");
+
+        let endorsements: CodeList<Endorsement> = CodeList::parse("H,T", Some("HAZMAT AND DOUBLES".to_string()));
+        assert!(endorsements.contains(&Endorsement::HazardousMaterials));
+        assert_eq!(endorsements.describe(), "HAZMAT AND DOUBLES");
+
+        let classes: CodeList<VehicleClass> = CodeList::parse("DM", None);
+        assert_eq!(classes.codes, vec![VehicleClass::Other("D".to_string()), VehicleClass::M]);
+        assert!(CodeList::<Restriction>::parse("NONE", None).codes.is_empty());
+
+        let classes: CodeList<VehicleClass> = CodeList::parse_jurisdiction("C1", None);
+        assert_eq!(classes.codes, vec![VehicleClass::Other("C1".to_string())]);
+
+        let classes: CodeList<VehicleClass> = CodeList::parse_jurisdiction("A,M", None);
+        assert_eq!(classes.codes, vec![VehicleClass::Other("A".to_string()), VehicleClass::Other("M".to_string())]);
+
+        let classes: CodeList<VehicleClass> = CodeList::parse_jurisdiction("C", None);
+        assert_eq!(classes.codes, vec![VehicleClass::Other("C".to_string())]);
+        assert_eq!(classes.describe(), "C");
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{Datelike, NaiveDate};
+
+/// The AAMVA version of the 2013 standard, cards written to older versions often predate the REAL ID elements.
+const VERSION_2013: u8 = 8;
+
+const YEAR_2013: i32 = 2013;
+
+/// What DDA says about the card's compliance with the REAL ID Act.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compliance {
+    /// DDA is "F", the card is REAL ID compliant.
+    Compliant,
+    /// DDA is "N" on a card designed to the 2013 standard or later, the card is not REAL ID compliant.
+    NonCompliant,
+    /// The card carries no DDA or a value the standard does not define, or says "N" on a card design that predates
+    /// the 2013 standard, where it does not tell a non-compliant card from one issued before REAL ID.
+    Unknown
+}
+
+/// REAL ID compliance of a card, built from DDA, DDB, DDD and the AAMVA version of the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplianceStatus {
+    pub compliance: Compliance,
+    /// Whether the card was issued for the limited duration of the holder's lawful status (DDD), unknown when
+    /// the compliance of the card is unknown.
+    pub limited_duration: Option<bool>,
+    /// The date the design of the card was last revised (DDB).
+    pub card_revision_date: Option<NaiveDate>,
+    pub aamva_version: u8
+}
+
+impl ComplianceStatus {
+
+    pub fn new(compliance_type: Option<&str>, limited_duration_indicator: Option<&str>, card_revision_date: Option<NaiveDate>, aamva_version: u8) -> ComplianceStatus {
+        let predates_2013_standard = predates_2013_standard(card_revision_date, aamva_version);
+
+        let compliance = match compliance_type.map(str::trim) {
+            Some("F") => Compliance::Compliant,
+            Some("N") if !predates_2013_standard => Compliance::NonCompliant,
+            _ => Compliance::Unknown
+        };
+
+        // DDD is only written as "1" on limited duration cards, so its absence means a full term card.
+        let limited_duration = match (compliance, limited_duration_indicator.map(str::trim)) {
+            (_, Some("1")) => Some(true),
+            (Compliance::Unknown, _) => None,
+            _ => Some(false)
+        };
+
+        ComplianceStatus { compliance, limited_duration, card_revision_date, aamva_version }
+    }
+
+    /// `None` when the card does not say, which includes every card without DDA and an "N" on a card design that
+    /// predates the 2013 standard.
+    pub fn is_real_id_compliant(&self) -> Option<bool> {
+        match self.compliance {
+            Compliance::Compliant => Some(true),
+            Compliance::NonCompliant => Some(false),
+            Compliance::Unknown => None
+        }
+    }
+
+    pub fn is_limited_duration(&self) -> Option<bool> {
+        self.limited_duration
+    }
+
+    /// Whether the card was written to a version of the standard older than 2013, or its design was last revised
+    /// (DDB) before 2013, when the REAL ID elements were not yet in common use.
+    pub fn predates_2013_standard(&self) -> bool {
+        predates_2013_standard(self.card_revision_date, self.aamva_version)
+    }
+}
+
+fn predates_2013_standard(card_revision_date: Option<NaiveDate>, aamva_version: u8) -> bool {
+    aamva_version < VERSION_2013 || card_revision_date.is_some_and(|date| date.year() < YEAR_2013)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::compliance::{Compliance, ComplianceStatus};
+
+    #[test]
+    fn test_status() {
+        let revision = NaiveDate::from_ymd_opt(2018, 4, 2);
+
+        let status = ComplianceStatus::new(Some("F"), None, revision, 9);
+        assert_eq!(status.is_real_id_compliant(), Some(true));
+        assert_eq!(status.is_limited_duration(), Some(false));
+        assert_eq!(status.card_revision_date, revision);
+
+        let status = ComplianceStatus::new(Some("F"), Some("1"), revision, 9);
+        assert_eq!(status.is_limited_duration(), Some(true));
+
+        let status = ComplianceStatus::new(Some("N"), None, None, 9);
+        assert_eq!(status.compliance, Compliance::NonCompliant);
+        assert_eq!(status.is_real_id_compliant(), Some(false));
+    }
+
+    #[test]
+    fn test_unknown() {
+        let status = ComplianceStatus::new(None, None, None, 5);
+        assert!(status.predates_2013_standard());
+        assert_eq!(status.is_real_id_compliant(), None);
+        assert_eq!(status.is_limited_duration(), None);
+
+        assert_eq!(ComplianceStatus::new(Some("X"), None, None, 9).compliance, Compliance::Unknown);
+    }
+
+    #[test]
+    fn test_older_designs() {
+        let status = ComplianceStatus::new(Some("N"), None, None, 5);
+        assert_eq!(status.compliance, Compliance::Unknown);
+        assert_eq!(status.is_limited_duration(), None);
+
+        let status = ComplianceStatus::new(Some("N"), None, NaiveDate::from_ymd_opt(2011, 6, 1), 9);
+        assert!(status.predates_2013_standard());
+        assert_eq!(status.is_real_id_compliant(), None);
+
+        assert_eq!(ComplianceStatus::new(Some("F"), None, None, 5).compliance, Compliance::Compliant);
+    }
+}
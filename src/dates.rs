@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+
+use crate::error::Error;
+use crate::jurisdiction::{Country, Jurisdiction};
+
+/// The order in which the digits of a date element are written on the card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat {
+    /// MMDDCCYY, used by jurisdictions in the United States.
+    MonthDayYear,
+    /// CCYYMMDD, used by Canadian jurisdictions and by every card written to version 01 of the standard.
+    YearMonthDay
+}
+
+impl DateFormat {
+
+    /// Picks the format of the dates on a card, version 01 always used CCYYMMDD. Later versions follow the country
+    /// of the document (DCG), falling back to the country of the issuing jurisdiction when DCG is absent.
+    pub fn detect(country_identification: Option<&str>, jurisdiction: Option<Jurisdiction>, aamva_version: u8) -> DateFormat {
+        if aamva_version == 1 {
+            return DateFormat::YearMonthDay;
+        }
+
+        let country = country_identification
+            .and_then(|code| Country::from_code(code.trim()))
+            .or(jurisdiction.map(|jurisdiction| jurisdiction.country()));
+
+        match country {
+            Some(Country::Canada) => DateFormat::YearMonthDay,
+            _ => DateFormat::MonthDayYear
+        }
+    }
+
+    /// Parses the eight digits of a date element, `element_id` is only used to describe the error.
+    pub fn parse(&self, element_id: &str, value: &str) -> Result<NaiveDate, Error> {
+        let invalid = |reason: String| Error::InvalidDate {
+            element_id: element_id.to_string(),
+            value: value.to_string(),
+            reason
+        };
+
+        if value.len() != 8 || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid("expected eight digits".to_string()));
+        }
+
+        let (year, month, day) = match self {
+            DateFormat::MonthDayYear => (&value[4..8], &value[0..2], &value[2..4]),
+            DateFormat::YearMonthDay => (&value[0..4], &value[4..6], &value[6..8])
+        };
+
+        // Jurisdictions write all zeros, all nines or a zero month or day when the date is not known.
+        if value.bytes().all(|byte| byte == b'0') || value.bytes().all(|byte| byte == b'9') || month == "00" || day == "00" {
+            return Err(Error::UnknownDate { element_id: element_id.to_string(), value: value.to_string() });
+        }
+
+        let (year, month, day): (i32, u32, u32) = (year.parse().unwrap_or(0), month.parse().unwrap_or(0), day.parse().unwrap_or(0));
+
+        if !(1..=12).contains(&month) {
+            return Err(invalid(format!("month {month} does not exist")));
+        }
+
+        NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| invalid(format!("day {day} does not exist in month {month} of {year}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::dates::DateFormat;
+    use crate::error::Error;
+    use crate::jurisdiction::Jurisdiction;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(DateFormat::detect(Some("USA"), None, 9), DateFormat::MonthDayYear);
+        assert_eq!(DateFormat::detect(Some("CAN"), Some(Jurisdiction::California), 9), DateFormat::YearMonthDay);
+        assert_eq!(DateFormat::detect(None, Some(Jurisdiction::Ontario), 9), DateFormat::YearMonthDay);
+        assert_eq!(DateFormat::detect(None, None, 9), DateFormat::MonthDayYear);
+        assert_eq!(DateFormat::detect(Some("USA"), Some(Jurisdiction::California), 1), DateFormat::YearMonthDay);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(DateFormat::MonthDayYear.parse("DBB", "08311977").unwrap(), NaiveDate::from_ymd_opt(1977, 8, 31).unwrap());
+        assert_eq!(DateFormat::YearMonthDay.parse("DBB", "19770831").unwrap(), NaiveDate::from_ymd_opt(1977, 8, 31).unwrap());
+        assert_eq!(DateFormat::MonthDayYear.parse("DBB", "02292000").unwrap(), NaiveDate::from_ymd_opt(2000, 2, 29).unwrap());
+
+        assert!(matches!(DateFormat::MonthDayYear.parse("DBB", "02301990"), Err(Error::InvalidDate { .. })));
+        assert!(matches!(DateFormat::MonthDayYear.parse("DBB", "02291900"), Err(Error::InvalidDate { .. })));
+        assert!(matches!(DateFormat::MonthDayYear.parse("DBB", "13011990"), Err(Error::InvalidDate { .. })));
+        assert!(matches!(DateFormat::MonthDayYear.parse("DBB", "0101199"), Err(Error::InvalidDate { .. })));
+
+        assert!(matches!(DateFormat::MonthDayYear.parse("DBA", "00000000"), Err(Error::UnknownDate { .. })));
+        assert!(matches!(DateFormat::MonthDayYear.parse("DBA", "99999999"), Err(Error::UnknownDate { .. })));
+        assert!(matches!(DateFormat::YearMonthDay.parse("DBB", "19770000"), Err(Error::UnknownDate { .. })));
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The value was kept, but looks wrong.
+    Warning,
+    /// The value breaks the AAMVA standard and was left out of the document, or the card holds conflicting values
+    /// for the element.
+    Error
+}
+
+impl Severity {
+
+    /// The usual severity of an error, values that were kept but look wrong are warnings.
+    pub fn of(error: &Error) -> Severity {
+        match error {
+            Error::UnknownElement { .. }
+            | Error::UnknownVersion { .. }
+            | Error::UnknownCode { .. }
+            | Error::WeightMismatch { .. }
+            | Error::AgeThresholdMismatch { .. }
+            | Error::DuplicateElement { conflicting: false, .. }
+            | Error::UnexpectedSeparators { .. } => Severity::Warning,
+            _ => Severity::Error
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error")
+        }
+    }
+}
+
+/// A problem found while reading a card. Diagnostics leave out the values on the card unless
+/// `ParserOptions::include_values` asks for them, the span can be used to look the element up in `Parsed::decoded`
+/// when the value is needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// A stable, kebab case name for the kind of problem, such as `unknown-element` or `invalid-date`.
+    pub code: &'static str,
+    pub element_id: Option<String>,
+    /// The byte range of the element in the decoded file, `Parsed::decoded`, ID included.
+    pub span: Option<Range<usize>>,
+    pub message: String
+}
+
+impl Diagnostic {
+
+    pub fn new(severity: Severity, error: &Error, element_id: Option<&str>, span: Option<Range<usize>>) -> Diagnostic {
+        Diagnostic {
+            severity,
+            code: code(error),
+            element_id: element_id.or_else(|| self::element_id(error)).map(str::to_string),
+            span,
+            message: message(error)
+        }
+    }
+
+    /// Replaces the message with the full message of the error, quoting the value from the card.
+    pub fn with_value(mut self, error: &Error) -> Diagnostic {
+        self.message = error.to_string();
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// Somewhere to send diagnostics, such as a log.
+pub trait DiagnosticSink {
+    fn report(&mut self, diagnostic: &Diagnostic);
+}
+
+impl DiagnosticSink for Vec<Diagnostic> {
+    fn report(&mut self, diagnostic: &Diagnostic) {
+        self.push(diagnostic.clone());
+    }
+}
+
+/// Sends diagnostics to the `log` crate under the `aamva` target.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogSink;
+
+#[cfg(feature = "log")]
+impl DiagnosticSink for LogSink {
+    fn report(&mut self, diagnostic: &Diagnostic) {
+        let level = match diagnostic.severity {
+            Severity::Warning => log::Level::Warn,
+            Severity::Error => log::Level::Error
+        };
+
+        log::log!(target: "aamva", level, "{diagnostic}");
+    }
+}
+
+/// Sends diagnostics to `tracing` as events with the code, element ID and span as fields.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingSink;
+
+#[cfg(feature = "tracing")]
+impl DiagnosticSink for TracingSink {
+    fn report(&mut self, diagnostic: &Diagnostic) {
+        let element_id = diagnostic.element_id.as_deref().unwrap_or_default();
+        let span = diagnostic.span.as_ref().map(|span| format!("{span:?}")).unwrap_or_default();
+
+        match diagnostic.severity {
+            Severity::Warning => tracing::warn!(target: "aamva", code = diagnostic.code, element_id, span, "{}", diagnostic.message),
+            Severity::Error => tracing::error!(target: "aamva", code = diagnostic.code, element_id, span, "{}", diagnostic.message)
+        }
+    }
+}
+
+/// The byte range of `slice` in `file`, `None` when it was not borrowed from it.
+pub(crate) fn span_in(file: &str, slice: &str) -> Option<Range<usize>> {
+    let start = (slice.as_ptr() as usize).checked_sub(file.as_ptr() as usize)?;
+    let end = start + slice.len();
+
+    (end <= file.len()).then_some(start..end)
+}
+
+fn code(error: &Error) -> &'static str {
+    match error {
+        Error::UnknownElement { .. } => "unknown-element",
+        Error::UnknownVersion { .. } => "unknown-version",
+        Error::DocumentTypeInvalid { .. } => "document-type-invalid",
+        Error::InvalidStringLength { .. } => "invalid-length",
+        Error::InvalidCharacter { .. } => "invalid-character",
+        Error::InvalidDate { .. } => "invalid-date",
+        Error::UnknownDate { .. } => "unknown-date",
+        Error::InvalidHeight { .. } => "invalid-height",
+        Error::UnknownCode { .. } => "unknown-code",
+        Error::WeightMismatch { .. } => "weight-mismatch",
+        Error::TruncatedNameTooShort { .. } => "truncated-name-too-short",
+        Error::AgeThresholdMismatch { .. } => "age-threshold-mismatch",
+        Error::DuplicateElement { conflicting: true, .. } => "conflicting-element",
+        Error::DuplicateElement { .. } => "duplicate-element",
+        Error::UnexpectedSeparators { .. } => "unexpected-separators",
+        Error::InvalidFileType | Error::HeaderTooShort { .. } | Error::InvalidNumber { .. } | Error::OutOfRange { .. } => "invalid-header",
+        Error::InvalidSubfileType { .. } | Error::SubfileOutOfBounds { .. } | Error::SubfileTypeMismatch { .. } => "invalid-subfile-designator",
+        _ => "invalid-file"
+    }
+}
+
+fn element_id(error: &Error) -> Option<&str> {
+    match error {
+        Error::UnknownElement { element_id, .. }
+        | Error::DocumentTypeInvalid { element_id, .. }
+        | Error::InvalidStringLength { element_id, .. }
+        | Error::InvalidCharacter { element_id, .. }
+        | Error::InvalidDate { element_id, .. }
+        | Error::UnknownDate { element_id, .. }
+        | Error::UnknownCode { element_id, .. }
+        | Error::TruncatedNameTooShort { element_id, .. }
+        | Error::AgeThresholdMismatch { element_id, .. }
+        | Error::DuplicateElement { element_id, .. } => Some(element_id),
+        _ => None
+    }
+}
+
+// The messages of errors that quote a value from the card, with the value left out.
+fn message(error: &Error) -> String {
+    match error {
+        Error::InvalidCharacter { element_id, position, .. } => format!("Element '{element_id}' holds a character it does not allow at position {position}"),
+        Error::InvalidDate { element_id, .. } => format!("Element '{element_id}' holds an invalid date"),
+        Error::UnknownDate { element_id, .. } => format!("Element '{element_id}' marks the date as unknown"),
+        Error::InvalidHeight { .. } => "The height is not a valid height".to_string(),
+        Error::UnknownCode { element_id, .. } => format!("Element '{element_id}' holds a code that is not in the AAMVA code table"),
+        Error::WeightMismatch { range, .. } => format!("The weight does not fall in the weight range {range} of DCE"),
+        Error::AgeThresholdMismatch { element_id, .. } => format!("Element '{element_id}' disagrees with the date of birth"),
+        Error::InvalidNumber { field, .. } => format!("Header field '{field}' must be numeric"),
+        _ => error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::{span_in, Diagnostic, Severity};
+    use crate::error::Error;
+
+    #[test]
+    fn test_values_are_left_out() {
+        let error = Error::InvalidDate { element_id: "DBB".to_string(), value: "02301977".to_string(), reason: "no such day".to_string() };
+        let diagnostic = Diagnostic::new(Severity::Error, &error, None, Some(10..21));
+
+        assert_eq!(diagnostic.code, "invalid-date");
+        assert_eq!(diagnostic.element_id.as_deref(), Some("DBB"));
+        assert!(!diagnostic.to_string().contains("1977"));
+        assert_eq!(diagnostic.to_string(), "error [invalid-date]: Element 'DBB' holds an invalid date");
+        assert!(diagnostic.with_value(&error).message.contains("02301977"));
+    }
+
+    #[test]
+    fn test_span_in() {
+        let file = String::from("@\nDAQD1234562\nDCSSAMPLE");
+        let line = file.split('\n').nth(2).unwrap();
+
+        assert_eq!(span_in(&file, line), Some(14..23));
+        assert_eq!(span_in(&file, "DCSSAMPLE"), None);
+    }
+}
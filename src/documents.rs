@@ -1,59 +1,524 @@
-use crate::elements::{JurisdictionSpecificVehicleClass, Element, JurisdictionSpecificRestrictionCodes, JurisdictionSpecificEndorsementCodes, DocumentExpirationDate, CustomerFirstName, CustomerFamilyName, DocumentIssueDate, CustomerMiddleNames, DateOfBirth, PhysicalDescriptionSex, PhysicalDescriptionEyeColor, PhysicalDescriptionHeight, AddressStreet1, AddressCity, AddressJurisdictionCode, AddressPostalCode, CustomerIdNumber, DocumentDescriminator, CountryIdentification, FamilyNameTruncation, FirstNameTruncation, MiddleNameTruncation, AddressStreet2, HairColor, PlaceOfBirth, AuditInformation, InventoryControlNumber, AliasFamilyName, AliasGivenName, AliasSuffixName, NameSuffix, PhysicalDescriptionWeightRange, RaceEthnicity, StandardVehicleClassification, StandardEndorsementCode, StandardRestrictionCode, JurisdictionSpecificVehicleClassificationDescription, JurisdictionSpecificEndorsementCodeDescription, JurisdictionSpecificRestrictionCodeDescription, DHSComplianceType, DHSCardRevisionDate, HAZMATEndorcementExpirationDate, LimitedDurationDocumentIndicator, WeightPounds, WeightKilograms, Under18Until, Under19Until, Under21Until, OrganDonorIndicator, VeteranIndicator, by_id};
+use crate::elements::{JurisdictionSpecificVehicleClass, Element, JurisdictionSpecificRestrictionCodes, JurisdictionSpecificEndorsementCodes, DocumentExpirationDate, CustomerFirstName, CustomerFamilyName, DocumentIssueDate, CustomerMiddleNames, DateOfBirth, PhysicalDescriptionSex, PhysicalDescriptionEyeColor, PhysicalDescriptionHeight, AddressStreet1, AddressCity, AddressJurisdictionCode, AddressPostalCode, CustomerIdNumber, DocumentDescriminator, CountryIdentification, FamilyNameTruncation, FirstNameTruncation, MiddleNameTruncation, AddressStreet2, HairColor, PlaceOfBirth, AuditInformation, InventoryControlNumber, AliasFamilyName, AliasGivenName, AliasSuffixName, NameSuffix, PhysicalDescriptionWeightRange, RaceEthnicity, StandardVehicleClassification, StandardEndorsementCode, StandardRestrictionCode, JurisdictionSpecificVehicleClassificationDescription, JurisdictionSpecificEndorsementCodeDescription, JurisdictionSpecificRestrictionCodeDescription, DHSComplianceType, DHSCardRevisionDate, HAZMATEndorcementExpirationDate, LimitedDurationDocumentIndicator, WeightPounds, WeightKilograms, Under18Until, Under19Until, Under21Until, OrganDonorIndicator, VeteranIndicator, DuplicateElement, UnknownElement, dictionary, duplicate_elements, split_element_line, unknown_elements, validate_lines};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate};
+
+use crate::age;
+use crate::clock::Clock;
+use crate::codes::{self, Code, CodeList, Endorsement, EyeColor, Restriction, Sex, VehicleClass};
+use crate::compliance::ComplianceStatus;
+use crate::dates::DateFormat;
+use crate::diagnostics::{self, Diagnostic, DiagnosticSink, Severity};
+use crate::encoding::{self, Decoding};
 use crate::header::Header;
+use crate::height::Height;
+use crate::jurisdiction::{Country, Jurisdiction};
+use crate::options::{DuplicateResolution, ParserOptions, Policy};
+use crate::name::{Name, NamePart, Truncation};
+use crate::postal::PostalCode;
+use crate::privileges::Privileges;
+use crate::record::Record;
+use crate::subfile::{JurisdictionSubfile, Subfile};
+use crate::versions::{normalize_lines, LATEST_VERSION};
+use crate::validity::{Expiry, Issue, Validity, ValidityUnknown};
+use crate::weight::{Weight, WeightRange};
 use crate::error::Error;
 
 pub trait Reader {
 
+    fn get_header_and_subfiles(file: String) -> Result<(Header, Vec<Subfile>), Error> {
+        Ok(Record::parse(&file)?.to_owned())
+    }
+
     fn get_header_and_lines(file: String) -> Result<(Header, Vec<String>), Error> {
-        let mut lines: Vec<String> = file.split("\n").map(|f| f.to_string()).collect();
+        let (header, subfiles) = Self::get_header_and_subfiles(file)?;
+
+        let lines = match subfiles.into_iter().find(|subfile| !subfile.is_jurisdiction_specific()) {
+            Some(subfile) => subfile.lines,
+            None => return Err(Error::SubfileNotFound { expected: "DL, ID or EN".to_string() })
+        };
+
+        Ok((
+            header,
+            lines
+        ))
+    }
+
+    fn from_lines<S: AsRef<str>>(lines: &[S], context: &mut ParseContext) -> Self where Self: Sized;
+
+    /// Parses the document, reading the card as strictly as the options ask, see `ParserOptions`.
+    fn new(file: String, options: &ParserOptions) -> Result<(Header, Self), Error> where Self: Sized {
+        let parsed = Self::read(file, options)?;
+
+        Ok((parsed.header, parsed.document))
+    }
+
+    /// Same as `new`, but takes the raw bytes of the barcode, which the AAMVA standard encodes as ISO-8859-1.
+    fn from_bytes(bytes: &[u8], options: &ParserOptions) -> Result<(Header, Self), Error> where Self: Sized {
+        let parsed = Self::read_bytes(bytes, options)?;
+
+        Ok((parsed.header, parsed.document))
+    }
+
+    /// Parses the document together with any jurisdiction specific subfiles that follow it.
+    fn read(file: String, options: &ParserOptions) -> Result<Parsed<Self>, Error> where Self: Sized {
+        let (file, decoding) = encoding::repair(file);
+
+        Self::read_decoded(file, decoding, options)
+    }
 
-        if &lines[0] != "@" {
-            return Err(Error::NonCompliance)
+    fn read_bytes(bytes: &[u8], options: &ParserOptions) -> Result<Parsed<Self>, Error> where Self: Sized {
+        let (file, decoding) = encoding::decode(bytes);
+
+        Self::read_decoded(file, decoding, options)
+    }
+
+    /// Reads a decoded file, failing when a problem breaks a rule the options reject.
+    fn read_decoded(file: String, decoding: Decoding, options: &ParserOptions) -> Result<Parsed<Self>, Error> where Self: Sized {
+        let mut context = ParseContext::new(LATEST_VERSION, None);
+        context.options = *options;
+
+        let record = Record::parse_with(&file, &mut context)?;
+
+        let subfile = match record.document() {
+            Some(subfile) => subfile,
+            None => return Err(Error::SubfileNotFound { expected: "DL, ID or EN".to_string() })
+        };
+
+        let mut aamva_version = record.header.aamva_version_number();
+
+        // A version without an element dictionary, such as one newer than this crate, is read as the latest version
+        // unless the options reject it.
+        if dictionary(aamva_version).is_none() {
+            context.recover(Error::UnknownVersion { aamva_version, read_as: LATEST_VERSION })?;
+
+            aamva_version = LATEST_VERSION;
         }
 
-        lines.remove(0);
+        let lines = context.resolve_duplicates(&subfile.lines);
 
-        let headline = &lines[0];
+        context.aamva_version = aamva_version;
+        context.jurisdiction = record.header.jurisdiction();
+        context.locate(&file, &lines);
+        context.collect_unknown_elements(&file, &subfile.lines);
+        context.collect_duplicate_elements(&file, &subfile.lines);
 
-        let header = Header::from(headline.to_string())?;
-        
-        lines.remove(0);
+        if let Some(document_type) = subfile.subfile_type().document_type() {
+            for violation in validate_lines(&lines, aamva_version, document_type) {
+                if is_legacy_code(&violation, &lines) {
+                    continue;
+                }
 
-        Ok((
+                if let Error::TruncatedNameTooShort { element_id, .. } = &violation {
+                    if !context.options.policy(&violation).keeps_value() {
+                        context.left_out.push(element_id.clone());
+                    }
+                }
+
+                context.report(None, violation);
+            }
+        }
+
+        let lines = normalize_lines(&lines, aamva_version);
+        let header = record.header.to_owned();
+
+        let jurisdiction_subfiles = record.subfiles
+            .iter()
+            .filter(|subfile| subfile.is_jurisdiction_specific())
+            .map(|subfile| JurisdictionSubfile::from(subfile.to_owned(), header.issuer_identification_number()))
+            .collect();
+
+        let document = Self::from_lines(&lines, &mut context);
+
+        if let Some(diagnostic) = context.rejected {
+            return Err(Error::Rejected { diagnostic });
+        }
+
+        Ok(Parsed {
+            document,
             header,
-            lines
-        ))
+            jurisdiction_subfiles,
+            decoding,
+            decoded: file,
+            diagnostics: context.diagnostics
+        })
+    }
+}
+
+/// A parsed document alongside its header and the jurisdiction specific subfiles found on the card.
+#[derive(Debug)]
+pub struct Parsed<T> {
+    pub header: Header,
+    pub document: T,
+    pub jurisdiction_subfiles: Vec<JurisdictionSubfile>,
+    pub decoding: Decoding,
+    /// The file after decoding and repair, which the spans of the diagnostics and of the unknown and duplicate
+    /// elements point into. It differs from the input when `decoding` is not `Decoding::Unchanged`.
+    pub decoded: String,
+    /// Errors for elements that break the character classes, lengths or document types declared by the AAMVA
+    /// standard, hold an impossible or unknown date, are names marked truncated that are shorter than their element,
+    /// or appear more than once with different values. Values that cannot be read are left out of the document.
+    /// Warnings for elements the standard does not define and values that were kept but look wrong, such as codes
+    /// outside the AAMVA code tables or elements that disagree with each other.
+    pub diagnostics: Vec<Diagnostic>
+}
+
+impl<T> Parsed<T> {
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(Diagnostic::is_error)
     }
 
-    fn new(file: String) -> Result<(Header, Self), Error> where Self: Sized;
+    pub fn report_to(&self, sink: &mut impl DiagnosticSink) {
+        for diagnostic in &self.diagnostics {
+            sink.report(diagnostic);
+        }
+    }
+}
+
+/// What is known about the card while its elements are read, and the diagnostics found so far.
+#[derive(Debug)]
+pub struct ParseContext {
+    pub aamva_version: u8,
+    pub jurisdiction: Option<Jurisdiction>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub options: ParserOptions,
+    spans: Vec<(String, Range<usize>)>,
+    unknown_elements: Option<Vec<UnknownElement>>,
+    duplicate_elements: Option<Vec<DuplicateElement>>,
+    // Elements that are valid on their own but were left out because of their relation to another element.
+    left_out: Vec<String>,
+    rejected: Option<Diagnostic>
+}
+
+impl ParseContext {
+
+    pub fn new(aamva_version: u8, jurisdiction: Option<Jurisdiction>) -> ParseContext {
+        ParseContext {
+            aamva_version,
+            jurisdiction,
+            diagnostics: Vec::new(),
+            options: ParserOptions::default(),
+            spans: Vec::new(),
+            unknown_elements: None,
+            duplicate_elements: None,
+            left_out: Vec::new(),
+            rejected: None
+        }
+    }
+
+    /// Records a problem with an element as the options ask, with the span of the first line holding it when the
+    /// lines were located in the file. A problem the options reject fails `Reader::read`.
+    pub fn report(&mut self, element_id: Option<&str>, error: Error) {
+        let policy = self.options.policy(&error);
+
+        let severity = match policy {
+            Policy::Ignore => return,
+            Policy::Warn => Severity::Warning,
+            Policy::Report => Severity::of(&error),
+            Policy::Reject => Severity::Error
+        };
+
+        let mut diagnostic = Diagnostic::new(severity, &error, element_id, None);
+
+        if self.options.include_values {
+            diagnostic = diagnostic.with_value(&error);
+        }
+
+        diagnostic.span = diagnostic.element_id.as_deref()
+            .and_then(|element_id| self.spans.iter().find(|(id, _)| id == element_id))
+            .map(|(_, span)| span.clone());
+
+        if policy == Policy::Reject && self.rejected.is_none() {
+            self.rejected = Some(diagnostic.clone());
+        }
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Reports a problem with the header or separators that parsing can continue past, or returns it when the
+    /// options reject it.
+    pub fn recover(&mut self, error: Error) -> Result<(), Error> {
+        if self.options.policy(&error) == Policy::Reject {
+            return Err(error);
+        }
+
+        self.report(None, error);
+
+        Ok(())
+    }
+
+    /// The raw value of an element, or `None` when it breaks the character class or length of the element, or is a
+    /// name marked truncated that is too short, and the options leave such values out. The problem itself is reported
+    /// by `validate_lines`.
+    pub fn value<E: Element, S: AsRef<str>>(&self, lines: &[S]) -> Option<String> {
+        if self.left_out.iter().any(|element_id| element_id == E::data().id) {
+            return None;
+        }
+
+        let value = E::find_unchecked_in_lines(lines)?;
+
+        match E::data().check(&value) {
+            Ok(()) => Some(value),
+            Err(error) => self.options.policy(&error).keeps_value().then_some(value)
+        }
+    }
+
+    /// The value of a text element with its padding removed, see `value`.
+    pub fn text<E: Element, S: AsRef<str>>(&self, lines: &[S]) -> Option<String> {
+        self.value::<E, S>(lines).map(E::remove_padding)
+    }
+
+    fn locate(&mut self, file: &str, lines: &[&str]) {
+        self.spans = lines.iter()
+            .filter_map(|line| Some((split_element_line(line).0.to_string(), diagnostics::span_in(file, line)?)))
+            .collect();
+    }
+
+    /// The elements of the document that its AAMVA version does not define. `Reader::read` collects them from the
+    /// lines as they were encoded, otherwise they are looked for in `lines`.
+    pub fn unknown_elements<S: AsRef<str>>(&mut self, lines: &[S]) -> Vec<UnknownElement> {
+        self.unknown_elements.take().unwrap_or_else(|| unknown_elements(lines, self.aamva_version))
+    }
+
+    fn collect_unknown_elements(&mut self, file: &str, lines: &[&str]) {
+        let mut elements = unknown_elements(lines, self.aamva_version);
+
+        for element in &mut elements {
+            element.span = diagnostics::span_in(file, lines[element.position]);
+
+            let error = Error::UnknownElement { element_id: element.id.clone(), aamva_version: self.aamva_version };
+            self.report(None, error);
+        }
+
+        self.unknown_elements = Some(elements);
+    }
+
+    /// The elements that appear more than once on the document, see `unknown_elements`.
+    pub fn duplicate_elements<S: AsRef<str>>(&mut self, lines: &[S]) -> Vec<DuplicateElement> {
+        self.duplicate_elements.take().unwrap_or_else(|| duplicate_elements(lines))
+    }
+
+    fn collect_duplicate_elements(&mut self, file: &str, lines: &[&str]) {
+        let mut elements = duplicate_elements(lines);
+
+        for element in &mut elements {
+            for occurrence in &mut element.occurrences {
+                occurrence.span = diagnostics::span_in(file, lines[occurrence.position]);
+            }
+
+            let error = Error::DuplicateElement {
+                element_id: element.id.clone(),
+                occurrences: element.occurrences.len(),
+                conflicting: element.is_conflicting()
+            };
+            self.report(None, error);
+        }
+
+        self.duplicate_elements = Some(elements);
+    }
+
+    // Keeps one line for each element ID, the first or last occurrence as the options ask.
+    fn resolve_duplicates<'a>(&self, lines: &[&'a str]) -> Vec<&'a str> {
+        let mut kept: HashMap<&str, usize> = HashMap::with_capacity(lines.len());
+
+        for (position, line) in lines.iter().enumerate() {
+            let element_id = split_element_line(line).0;
+
+            match self.options.duplicate_resolution {
+                DuplicateResolution::FirstWins => { kept.entry(element_id).or_insert(position); },
+                DuplicateResolution::LastWins => { kept.insert(element_id, position); }
+            }
+        }
+
+        lines.iter()
+            .enumerate()
+            .filter(|(position, line)| kept.get(split_element_line(line).0) == Some(position))
+            .map(|(_, line)| *line)
+            .collect()
+    }
+
+    /// The format of the dates on the card, see `DateFormat::detect`.
+    pub fn date_format<S: AsRef<str>>(&self, lines: &[S]) -> DateFormat {
+        let country_identification = self.text::<CountryIdentification, S>(lines);
+
+        DateFormat::detect(country_identification.as_deref(), self.jurisdiction, self.aamva_version)
+    }
+
+    /// Reads a date element, recording impossible and unknown dates as errors. Elements that break their
+    /// character class or length are already reported by `validate_lines` and are skipped silently.
+    pub fn date<E: Element, S: AsRef<str>>(&mut self, lines: &[S], format: DateFormat) -> Option<NaiveDate> {
+        let value = self.value::<E, S>(lines)?;
+
+        self.keep(E::data().id, format.parse(E::data().id, value.trim()))
+    }
+
+    /// Reads one of the T, N or U truncation indicators, in the same way as `date`.
+    pub fn truncation<E: Element, S: AsRef<str>>(&mut self, lines: &[S]) -> Option<Truncation> {
+        let value = self.value::<E, S>(lines)?;
+
+        self.keep(E::data().id, Truncation::parse(E::data().id, &value))
+    }
+
+    /// Reads an element into a typed value, recording values that do not parse as errors. Like `date`, elements
+    /// that already failed validation are skipped silently.
+    pub fn parse<E: Element, T: FromStr<Err = Error>, S: AsRef<str>>(&mut self, lines: &[S]) -> Option<T> {
+        let value = self.value::<E, S>(lines)?;
+
+        self.keep(E::data().id, value.parse())
+    }
+
+    /// Reads an element holding an AAMVA code. Codes are read as encoded, so that legacy spellings that break the
+    /// length or character class of the element are still understood, and codes outside the table are kept with a
+    /// warning.
+    pub fn code<E: Element, T: Code, S: AsRef<str>>(&mut self, lines: &[S]) -> Option<T> {
+        let value = E::find_unchecked_in_lines(lines)?;
+        let value = value.trim();
+
+        if value.is_empty() {
+            return None;
+        }
+
+        match T::from_code(value) {
+            Some(code) => Some(code),
+            None => {
+                self.report(None, Error::UnknownCode { element_id: E::data().id.to_string(), value: value.to_string() });
+                Some(T::other(value))
+            }
+        }
+    }
+
+    /// Combines DAW, DAX and DCE into one weight, warning when the exact weight falls outside the range code.
+    pub fn weight<S: AsRef<str>>(&mut self, lines: &[S]) -> Option<Weight> {
+        let number = |value: String| value.trim().parse::<u16>().ok();
+
+        let weight = Weight::from_elements(
+            self.value::<WeightPounds, S>(lines).and_then(number),
+            self.value::<WeightKilograms, S>(lines).and_then(number),
+            self.value::<PhysicalDescriptionWeightRange, S>(lines).and_then(number).and_then(|code| WeightRange::new(u8::try_from(code).ok()?))
+        )?;
+
+        if let (false, Some(range)) = (weight.is_consistent(), weight.range()) {
+            self.report(Some(PhysicalDescriptionWeightRange::data().id), Error::WeightMismatch { weight: weight.to_string(), range: range.code() });
+        }
+
+        Some(weight)
+    }
+
+    /// Splits a standard class, endorsement or restriction element into its codes, see `CodeList::parse`.
+    pub fn code_list<E: Element, T: Code, S: AsRef<str>>(&self, lines: &[S], description: Option<String>) -> Option<CodeList<T>> {
+        let value = self.value::<E, S>(lines)?;
+
+        Some(CodeList::parse(&value, description))
+    }
+
+    /// Splits a jurisdiction class, endorsement or restriction element into its codes, see
+    /// `CodeList::parse_jurisdiction`.
+    pub fn jurisdiction_code_list<E: Element, T: Code, S: AsRef<str>>(&self, lines: &[S], description: Option<String>) -> Option<CodeList<T>> {
+        let value = self.value::<E, S>(lines)?;
+
+        Some(CodeList::parse_jurisdiction(&value, description))
+    }
+
+    /// The free text of a description element, trimmed but with its spaces kept.
+    pub fn description<E: Element, S: AsRef<str>>(&self, lines: &[S]) -> Option<String> {
+        let value = self.value::<E, S>(lines)?;
+
+        Some(value.trim().to_string()).filter(|value| !value.is_empty())
+    }
+
+    /// The jurisdiction from the header IIN, or else from DAJ.
+    pub fn issuing_jurisdiction<S: AsRef<str>>(&self, lines: &[S]) -> Option<Jurisdiction> {
+        if self.jurisdiction.is_some() {
+            return self.jurisdiction;
+        }
+
+        let code = self.value::<AddressJurisdictionCode, S>(lines)?;
+        let country = self.value::<CountryIdentification, S>(lines).and_then(|code| Country::from_code(code.trim()));
+
+        match country {
+            Some(country) => Jurisdiction::from_code_in(&code, country),
+            None => Jurisdiction::from_code(&code)
+        }
+    }
+
+    /// REAL ID compliance from DDA, DDB and DDD, see `ComplianceStatus`.
+    pub fn compliance<S: AsRef<str>>(&self, lines: &[S], card_revision_date: Option<NaiveDate>) -> ComplianceStatus {
+        ComplianceStatus::new(
+            self.value::<DHSComplianceType, S>(lines).as_deref(),
+            self.value::<LimitedDurationDocumentIndicator, S>(lines).as_deref(),
+            card_revision_date,
+            self.aamva_version
+        )
+    }
+
+    fn keep<T>(&mut self, element_id: &str, result: Result<T, Error>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.report(Some(element_id), error);
+                None
+            }
+        }
+    }
+}
+
+impl Default for ParseContext {
+    fn default() -> ParseContext {
+        ParseContext::new(LATEST_VERSION, None)
+    }
+}
+
+// Whether a violation is only a legacy spelling of a code, such as "F" in DBC or "BROWN" in DAY, which breaks the
+// element but is read by `ParseContext::code` as the code it stands for.
+fn is_legacy_code(violation: &Error, lines: &[&str]) -> bool {
+    let element_id = match violation {
+        Error::InvalidCharacter { element_id, .. } | Error::InvalidStringLength { element_id, .. } => element_id.as_str(),
+        _ => return false
+    };
+
+    let value = match lines.iter().find_map(|line| line.strip_prefix(element_id)) {
+        Some(value) => value,
+        None => return false
+    };
+
+    match element_id {
+        "DBC" => Sex::from_code(value).is_some(),
+        "DAY" => EyeColor::from_code(value).is_some(),
+        "DAZ" => codes::HairColor::from_code(value).is_some(),
+        "DCL" => codes::RaceEthnicity::from_code(value).is_some(),
+        _ => false
+    }
 }
 
 #[derive(Debug)]
 pub struct DriversLicense {
-    pub jurisdiction_specific_vehicle_class:                    Option<String>,
-    pub jurisdiction_specific_restriction_codes:                Option<String>,
-    pub jurisdiction_specific_endorsement_codes:                Option<String>,
-    pub document_expiration_date:                               Option<String>,
+    pub jurisdiction_specific_vehicle_class:                    Option<CodeList<VehicleClass>>,
+    pub jurisdiction_specific_restriction_codes:                Option<CodeList<Restriction>>,
+    pub jurisdiction_specific_endorsement_codes:                Option<CodeList<Endorsement>>,
+    pub document_expiration_date:                               Option<NaiveDate>,
     pub customer_family_name:                                   Option<String>,
     pub customer_first_name:                                    Option<String>,
-    pub document_issue_date:                                    Option<String>,
+    pub document_issue_date:                                    Option<NaiveDate>,
     pub customer_middle_names:                                  Option<String>,
-    pub date_of_birth:                                          Option<String>,
-    pub physical_description_sex:                               Option<String>,
-    pub physical_description_eye_color:                         Option<String>,
-    pub physical_description_height:                            Option<String>,
+    pub date_of_birth:                                          Option<NaiveDate>,
+    pub physical_description_sex:                               Option<Sex>,
+    pub physical_description_eye_color:                         Option<EyeColor>,
+    pub physical_description_height:                            Option<Height>,
     pub address_street_1:                                       Option<String>,
     pub address_city:                                           Option<String>,
     pub address_jurisdiction_code:                              Option<String>,
-    pub address_postal_code:                                    Option<String>,
+    pub address_postal_code:                                    Option<PostalCode>,
     pub customer_id_number:                                     Option<String>,
     pub document_descriminator:                                 Option<String>,
     pub country_identification:                                 Option<String>,
-    pub family_name_truncation:                                 Option<String>,
-    pub first_name_truncation:                                  Option<String>,
-    pub middle_name_truncation:                                 Option<String>,
+    pub family_name_truncation:                                 Option<Truncation>,
+    pub first_name_truncation:                                  Option<Truncation>,
+    pub middle_name_truncation:                                 Option<Truncation>,
     pub address_street_2:                                       Option<String>,
-    pub hair_color:                                             Option<String>,
+    pub hair_color:                                             Option<codes::HairColor>,
     pub place_of_birth:                                         Option<String>,
     pub audit_information:                                      Option<String>,
     pub inventory_control_number:                               Option<String>,
@@ -61,121 +526,178 @@ pub struct DriversLicense {
     pub alias_given_name:                                       Option<String>,
     pub alias_suffix_name:                                      Option<String>,
     pub name_suffix:                                            Option<String>,
-    pub physical_description_weight_range:                      Option<String>,
-    pub race_ethnicity:                                         Option<String>,
-    pub standard_vehicle_classification:                        Option<String>,
-    pub standard_endorsement_code:                              Option<String>,
-    pub standard_restriction_code:                              Option<String>,
-    pub jurisdiction_vehicle_classification_description:        Option<String>,
-    pub jurisdiction_specific_edorsement_code_description:      Option<String>,
-    pub jurisdiction_specific_restriction_code_description:     Option<String>,
-    pub compliance_type:                                        Option<String>,
-    pub card_revision_date:                                     Option<String>,
-    pub hazmat_endorsement_expiration:                          Option<String>,
-    pub limited_duration_document_indicator:                    Option<String>,
-    pub weight_pounds:                                          Option<String>,
-    pub weight_kilograms:                                       Option<String>,
-    pub under_18_until:                                         Option<String>,
-    pub under_19_until:                                         Option<String>,
-    pub under_21_until:                                         Option<String>,
+    pub weight:                                                 Option<Weight>,
+    pub race_ethnicity:                                         Option<codes::RaceEthnicity>,
+    pub standard_vehicle_classification:                        Option<CodeList<VehicleClass>>,
+    pub standard_endorsement_code:                              Option<CodeList<Endorsement>>,
+    pub standard_restriction_code:                              Option<CodeList<Restriction>>,
+    pub compliance:                                             ComplianceStatus,
+    pub card_revision_date:                                     Option<NaiveDate>,
+    pub hazmat_endorsement_expiration:                          Option<NaiveDate>,
+    pub under_18_until:                                         Option<NaiveDate>,
+    pub under_19_until:                                         Option<NaiveDate>,
+    pub under_21_until:                                         Option<NaiveDate>,
     pub organ_donor_indicator:                                  Option<String>,
-    pub veteran_indicator:                                      Option<String>,    
+    pub veteran_indicator:                                      Option<String>,
+    pub issuing_jurisdiction:                                   Option<Jurisdiction>,
+    /// Elements the AAMVA version of the card does not define, in the order they appear.
+    pub unknown_elements:                                       Vec<UnknownElement>,
+    /// Elements that appear more than once, with every occurrence.
+    pub duplicate_elements:                                     Vec<DuplicateElement>
 }
 
 
 
+impl DriversLicense {
+
+    /// The driving privileges of the licence on the given day, see `Privileges`.
+    pub fn privileges(&self, as_of: NaiveDate) -> Privileges {
+        Privileges::new(self, as_of)
+    }
+
+    /// The family, first and middle names linked to their truncation indicators.
+    pub fn name(&self) -> Name {
+        Name {
+            family: NamePart::new(self.customer_family_name.as_deref(), self.family_name_truncation),
+            first: NamePart::new(self.customer_first_name.as_deref(), self.first_name_truncation),
+            middle: NamePart::new(self.customer_middle_names.as_deref(), self.middle_name_truncation)
+        }
+    }
+
+    /// Age of the cardholder in whole years on the given day, see `age::age_on`.
+    pub fn age_on(&self, date: NaiveDate) -> Option<u32> {
+        age::age_on(self.date_of_birth?, date)
+    }
+
+    /// Age of the cardholder today, according to `clock`.
+    pub fn age(&self, clock: &impl Clock) -> Option<u32> {
+        self.age_on(clock.today())
+    }
+
+    pub fn is_at_least(&self, years: u32, date: NaiveDate) -> Option<bool> {
+        Some(age::is_at_least(self.date_of_birth?, years, date))
+    }
+
+    /// The day the cardholder turns the given age, see `age::becomes_of_age`.
+    pub fn becomes_of_age(&self, years: u32) -> Option<NaiveDate> {
+        age::becomes_of_age(self.date_of_birth?, years)
+    }
+
+    /// The under 18, 19 and 21 dates that disagree with the date of birth, these are also reported as warnings
+    /// when the card is read.
+    pub fn age_threshold_mismatches(&self) -> Vec<Error> {
+        age::threshold_mismatches(self.date_of_birth, &[
+            ("DDH", 18, self.under_18_until),
+            ("DDI", 19, self.under_19_until),
+            ("DDJ", 21, self.under_21_until)
+        ])
+    }
+
+    /// The issue and expiration dates in the time zone of the issuing jurisdiction, see `Validity`.
+    pub fn validity(&self) -> Validity {
+        Validity::new(self.document_issue_date, self.document_expiration_date, self.issuing_jurisdiction)
+    }
+
+    /// Whether the card has expired at the time given by `clock`, allowing for an optional grace period.
+    pub fn is_expired(&self, clock: &impl Clock, grace_period: Option<Duration>) -> Expiry {
+        self.validity().expiry(clock, grace_period)
+    }
+
+    pub fn days_until_expiry(&self, clock: &impl Clock) -> Result<i64, ValidityUnknown> {
+        self.validity().days_until_expiry(clock)
+    }
+
+    /// Whether the issue date of the card is after the time given by `clock`.
+    pub fn is_not_yet_valid(&self, clock: &impl Clock) -> Issue {
+        self.validity().issue(clock)
+    }
+}
+
 impl Reader for DriversLicense {
-    fn new(file: String) -> Result<(Header, DriversLicense), Error> {
-
-        let (header, lines): (Header, Vec<String>) = Self::get_header_and_lines(file)?;
-
-        for line in &lines {
-            let (prefix, value): (&str, &str) = line.split_at(3);
-            match by_id(line.split_at(3).0.to_string()) {
-                Err(_err) => {
-                    println!("Warning invalid Element ID: '{prefix}' WITH VALUE: '{value}' please submit an issue to have the Element added.")
-                },
-                Ok(_) => {}
-            } 
+    fn from_lines<S: AsRef<str>>(lines: &[S], context: &mut ParseContext) -> DriversLicense {
+        let date_format = context.date_format(lines);
+        let card_revision_date = context.date::<DHSCardRevisionDate, S>(lines, date_format);
+
+        let drivers_license = DriversLicense {
+            jurisdiction_specific_vehicle_class:                context.jurisdiction_code_list::<JurisdictionSpecificVehicleClass, _, _>(lines, context.description::<JurisdictionSpecificVehicleClassificationDescription, _>(lines)),
+            jurisdiction_specific_restriction_codes:            context.jurisdiction_code_list::<JurisdictionSpecificRestrictionCodes, _, _>(lines, context.description::<JurisdictionSpecificRestrictionCodeDescription, _>(lines)),
+            jurisdiction_specific_endorsement_codes:            context.jurisdiction_code_list::<JurisdictionSpecificEndorsementCodes, _, _>(lines, context.description::<JurisdictionSpecificEndorsementCodeDescription, _>(lines)),
+            document_expiration_date:                           context.date::<DocumentExpirationDate, S>(lines, date_format),
+            customer_family_name:                               context.text::<CustomerFamilyName, S>(lines),
+            customer_first_name:                                context.text::<CustomerFirstName, S>(lines),
+            document_issue_date:                                context.date::<DocumentIssueDate, S>(lines, date_format),
+            customer_middle_names:                              context.text::<CustomerMiddleNames, S>(lines),
+            date_of_birth:                                      context.date::<DateOfBirth, S>(lines, date_format),
+            physical_description_sex:                           context.code::<PhysicalDescriptionSex, _, _>(lines),
+            physical_description_eye_color:                     context.code::<PhysicalDescriptionEyeColor, _, _>(lines),
+            physical_description_height:                        context.parse::<PhysicalDescriptionHeight, _, _>(lines),
+            address_street_1:                                   context.text::<AddressStreet1, S>(lines),
+            address_city:                                       context.text::<AddressCity, S>(lines),
+            address_jurisdiction_code:                          context.text::<AddressJurisdictionCode, S>(lines),
+            address_postal_code:                                context.value::<AddressPostalCode, _>(lines).and_then(|value| PostalCode::parse(&value)),
+            customer_id_number:                                 context.text::<CustomerIdNumber, S>(lines),
+            document_descriminator:                             context.text::<DocumentDescriminator, S>(lines),
+            country_identification:                             context.text::<CountryIdentification, S>(lines),
+            family_name_truncation:                             context.truncation::<FamilyNameTruncation, _>(lines),
+            first_name_truncation:                              context.truncation::<FirstNameTruncation, _>(lines),
+            middle_name_truncation:                             context.truncation::<MiddleNameTruncation, _>(lines),
+            address_street_2:                                   context.text::<AddressStreet2, S>(lines),
+            hair_color:                                         context.code::<HairColor, _, _>(lines),
+            place_of_birth:                                     context.text::<PlaceOfBirth, S>(lines),
+            audit_information:                                  context.text::<AuditInformation, S>(lines),
+            inventory_control_number:                           context.text::<InventoryControlNumber, S>(lines),
+            alias_family_name:                                  context.text::<AliasFamilyName, S>(lines),
+            alias_given_name:                                   context.text::<AliasGivenName, S>(lines),
+            alias_suffix_name:                                  context.text::<AliasSuffixName, S>(lines),
+            name_suffix:                                        context.text::<NameSuffix, S>(lines), 
+            weight:                                             context.weight(lines),
+            race_ethnicity:                                     context.code::<RaceEthnicity, _, _>(lines),
+            standard_vehicle_classification:                    context.code_list::<StandardVehicleClassification, _, _>(lines, None),
+            standard_endorsement_code:                          context.code_list::<StandardEndorsementCode, _, _>(lines, None),
+            standard_restriction_code:                          context.code_list::<StandardRestrictionCode, _, _>(lines, None),
+            compliance:                                         context.compliance(lines, card_revision_date),
+            card_revision_date,
+            hazmat_endorsement_expiration:                      context.date::<HAZMATEndorcementExpirationDate, S>(lines, date_format),
+            under_18_until:                                     context.date::<Under18Until, S>(lines, date_format),
+            under_19_until:                                     context.date::<Under19Until, S>(lines, date_format),
+            under_21_until:                                     context.date::<Under21Until, S>(lines, date_format),
+            organ_donor_indicator:                              context.text::<OrganDonorIndicator, S>(lines),
+            veteran_indicator:                                  context.text::<VeteranIndicator, S>(lines),
+            issuing_jurisdiction:                               context.issuing_jurisdiction(lines),
+            unknown_elements:                                   context.unknown_elements(lines),
+            duplicate_elements:                                 context.duplicate_elements(lines)
+        };
+
+        for mismatch in drivers_license.age_threshold_mismatches() {
+            context.report(None, mismatch);
         }
 
-        Ok((header, DriversLicense {
-            jurisdiction_specific_vehicle_class:                JurisdictionSpecificVehicleClass::no_error_find_in_lines(&lines),
-            jurisdiction_specific_restriction_codes:            JurisdictionSpecificRestrictionCodes::no_error_find_in_lines(&lines),
-            jurisdiction_specific_endorsement_codes:            JurisdictionSpecificEndorsementCodes::no_error_find_in_lines(&lines),
-            document_expiration_date:                           DocumentExpirationDate::no_error_find_in_lines(&lines),
-            customer_family_name:                               CustomerFamilyName::no_error_find_in_lines(&lines),
-            customer_first_name:                                CustomerFirstName::no_error_find_in_lines(&lines),
-            document_issue_date:                                DocumentIssueDate::no_error_find_in_lines(&lines),
-            customer_middle_names:                              CustomerMiddleNames::no_error_find_in_lines(&lines),
-            date_of_birth:                                      DateOfBirth::no_error_find_in_lines(&lines),
-            physical_description_sex:                           PhysicalDescriptionSex::no_error_find_in_lines(&lines),
-            physical_description_eye_color:                     PhysicalDescriptionEyeColor::no_error_find_in_lines(&lines),
-            physical_description_height:                        PhysicalDescriptionHeight::no_error_find_in_lines(&lines),
-            address_street_1:                                   AddressStreet1::no_error_find_in_lines(&lines),
-            address_city:                                       AddressCity::no_error_find_in_lines(&lines),
-            address_jurisdiction_code:                          AddressJurisdictionCode::no_error_find_in_lines(&lines),
-            address_postal_code:                                AddressPostalCode::no_error_find_in_lines(&lines),
-            customer_id_number:                                 CustomerIdNumber::no_error_find_in_lines(&lines),
-            document_descriminator:                             DocumentDescriminator::no_error_find_in_lines(&lines),
-            country_identification:                             CountryIdentification::no_error_find_in_lines(&lines),
-            family_name_truncation:                             FamilyNameTruncation::no_error_find_in_lines(&lines),
-            first_name_truncation:                              FirstNameTruncation::no_error_find_in_lines(&lines),
-            middle_name_truncation:                             MiddleNameTruncation::no_error_find_in_lines(&lines),
-            address_street_2:                                   AddressStreet2::no_error_find_in_lines(&lines),
-            hair_color:                                         HairColor::no_error_find_in_lines(&lines),
-            place_of_birth:                                     PlaceOfBirth::no_error_find_in_lines(&lines),
-            audit_information:                                  AuditInformation::no_error_find_in_lines(&lines),
-            inventory_control_number:                           InventoryControlNumber::no_error_find_in_lines(&lines),
-            alias_family_name:                                  AliasFamilyName::no_error_find_in_lines(&lines),
-            alias_given_name:                                   AliasGivenName::no_error_find_in_lines(&lines),
-            alias_suffix_name:                                  AliasSuffixName::no_error_find_in_lines(&lines),
-            name_suffix:                                        NameSuffix::no_error_find_in_lines(&lines), 
-            physical_description_weight_range:                  PhysicalDescriptionWeightRange::no_error_find_in_lines(&lines),
-            race_ethnicity:                                     RaceEthnicity::no_error_find_in_lines(&lines),
-            standard_vehicle_classification:                    StandardVehicleClassification::no_error_find_in_lines(&lines),
-            standard_endorsement_code:                          StandardEndorsementCode::no_error_find_in_lines(&lines),
-            standard_restriction_code:                          StandardRestrictionCode::no_error_find_in_lines(&lines),
-            jurisdiction_vehicle_classification_description:    JurisdictionSpecificVehicleClassificationDescription::no_error_find_in_lines(&lines),
-            jurisdiction_specific_edorsement_code_description:  JurisdictionSpecificEndorsementCodeDescription::no_error_find_in_lines(&lines),
-            jurisdiction_specific_restriction_code_description: JurisdictionSpecificRestrictionCodeDescription::no_error_find_in_lines(&lines),
-            compliance_type:                                    DHSComplianceType::no_error_find_in_lines(&lines),
-            card_revision_date:                                 DHSCardRevisionDate::no_error_find_in_lines(&lines),
-            hazmat_endorsement_expiration:                      HAZMATEndorcementExpirationDate::no_error_find_in_lines(&lines),
-            limited_duration_document_indicator:                LimitedDurationDocumentIndicator::no_error_find_in_lines(&lines),
-            weight_pounds:                                      WeightPounds::no_error_find_in_lines(&lines),
-            weight_kilograms:                                   WeightKilograms::no_error_find_in_lines(&lines),
-            under_18_until:                                     Under18Until::no_error_find_in_lines(&lines),
-            under_19_until:                                     Under19Until::no_error_find_in_lines(&lines),
-            under_21_until:                                     Under21Until::no_error_find_in_lines(&lines),
-            organ_donor_indicator:                              OrganDonorIndicator::no_error_find_in_lines(&lines),
-            veteran_indicator:                                  VeteranIndicator::no_error_find_in_lines(&lines)
-        }))
-    }   
+        drivers_license
+    }
 }
 
 pub struct IdentificationCard {
-    pub document_expiration_date:            Option<String>,
+    pub document_expiration_date:            Option<NaiveDate>,
     pub customer_family_name:                Option<String>,
     pub customer_first_name:                 Option<String>,
-    pub document_issue_date:                 Option<String>,
+    pub document_issue_date:                 Option<NaiveDate>,
     pub customer_middle_names:               Option<String>,
-    pub date_of_birth:                       Option<String>,
-    pub physical_description_sex:            Option<String>,
-    pub physical_description_eye_color:      Option<String>,
-    pub physical_description_height:         Option<String>,
+    pub date_of_birth:                       Option<NaiveDate>,
+    pub physical_description_sex:            Option<Sex>,
+    pub physical_description_eye_color:      Option<EyeColor>,
+    pub physical_description_height:         Option<Height>,
     pub address_street_1:                    Option<String>,
     pub address_city:                        Option<String>,
     pub address_jurisdiction_code:           Option<String>,
-    pub address_postal_code:                 Option<String>,
+    pub address_postal_code:                 Option<PostalCode>,
     pub customer_id_number:                  Option<String>,
     pub document_descriminator:              Option<String>,
     pub country_identification:              Option<String>,
-    pub family_name_truncation:              Option<String>,
-    pub first_name_truncation:               Option<String>,
-    pub middle_name_truncation:              Option<String>,
+    pub family_name_truncation:              Option<Truncation>,
+    pub first_name_truncation:               Option<Truncation>,
+    pub middle_name_truncation:              Option<Truncation>,
     pub address_street_2:                    Option<String>,
-    pub hair_color:                          Option<String>,
+    pub hair_color:                          Option<codes::HairColor>,
     pub place_of_birth:                      Option<String>,
     pub audit_information:                   Option<String>,
     pub inventory_control_number:            Option<String>,
@@ -183,77 +705,559 @@ pub struct IdentificationCard {
     pub alias_given_name:                    Option<String>,
     pub alias_suffix_name:                   Option<String>,
     pub name_suffix:                         Option<String>,
-    pub physical_description_weight_range:   Option<String>,
-    pub race_ethnicity:                      Option<String>,
-    pub compliance_type:                     Option<String>,
-    pub card_revision_date:                  Option<String>,
-    pub limited_duration_document_indicator: Option<String>,
-    pub weight_pounds:                       Option<String>,
-    pub weight_kilograms:                    Option<String>,
-    pub under_18_until:                      Option<String>,
-    pub under_19_until:                      Option<String>,
-    pub under_21_until:                      Option<String>,
+    pub weight:                              Option<Weight>,
+    pub race_ethnicity:                      Option<codes::RaceEthnicity>,
+    pub compliance:                          ComplianceStatus,
+    pub card_revision_date:                  Option<NaiveDate>,
+    pub under_18_until:                      Option<NaiveDate>,
+    pub under_19_until:                      Option<NaiveDate>,
+    pub under_21_until:                      Option<NaiveDate>,
     pub organ_donor_indicator:               Option<String>,
     pub veteran_indicator:                   Option<String>,
+    pub issuing_jurisdiction:                Option<Jurisdiction>,
+    /// Elements the AAMVA version of the card does not define, in the order they appear.
+    pub unknown_elements:                    Vec<UnknownElement>,
+    /// Elements that appear more than once, with every occurrence.
+    pub duplicate_elements:                  Vec<DuplicateElement>
+}
+
+impl IdentificationCard {
+
+    /// The family, first and middle names linked to their truncation indicators.
+    pub fn name(&self) -> Name {
+        Name {
+            family: NamePart::new(self.customer_family_name.as_deref(), self.family_name_truncation),
+            first: NamePart::new(self.customer_first_name.as_deref(), self.first_name_truncation),
+            middle: NamePart::new(self.customer_middle_names.as_deref(), self.middle_name_truncation)
+        }
+    }
+
+    /// Age of the cardholder in whole years on the given day, see `age::age_on`.
+    pub fn age_on(&self, date: NaiveDate) -> Option<u32> {
+        age::age_on(self.date_of_birth?, date)
+    }
+
+    /// Age of the cardholder today, according to `clock`.
+    pub fn age(&self, clock: &impl Clock) -> Option<u32> {
+        self.age_on(clock.today())
+    }
+
+    pub fn is_at_least(&self, years: u32, date: NaiveDate) -> Option<bool> {
+        Some(age::is_at_least(self.date_of_birth?, years, date))
+    }
+
+    /// The day the cardholder turns the given age, see `age::becomes_of_age`.
+    pub fn becomes_of_age(&self, years: u32) -> Option<NaiveDate> {
+        age::becomes_of_age(self.date_of_birth?, years)
+    }
+
+    /// The under 18, 19 and 21 dates that disagree with the date of birth, these are also reported as warnings
+    /// when the card is read.
+    pub fn age_threshold_mismatches(&self) -> Vec<Error> {
+        age::threshold_mismatches(self.date_of_birth, &[
+            ("DDH", 18, self.under_18_until),
+            ("DDI", 19, self.under_19_until),
+            ("DDJ", 21, self.under_21_until)
+        ])
+    }
+
+    /// The issue and expiration dates in the time zone of the issuing jurisdiction, see `Validity`.
+    pub fn validity(&self) -> Validity {
+        Validity::new(self.document_issue_date, self.document_expiration_date, self.issuing_jurisdiction)
+    }
+
+    /// Whether the card has expired at the time given by `clock`, allowing for an optional grace period.
+    pub fn is_expired(&self, clock: &impl Clock, grace_period: Option<Duration>) -> Expiry {
+        self.validity().expiry(clock, grace_period)
+    }
+
+    pub fn days_until_expiry(&self, clock: &impl Clock) -> Result<i64, ValidityUnknown> {
+        self.validity().days_until_expiry(clock)
+    }
+
+    /// Whether the issue date of the card is after the time given by `clock`.
+    pub fn is_not_yet_valid(&self, clock: &impl Clock) -> Issue {
+        self.validity().issue(clock)
+    }
 }
 
 impl Reader for IdentificationCard {
-    fn new(file: String) -> Result<(Header, IdentificationCard), Error> {
-        let (header, lines): (Header, Vec<String>) = Self::get_header_and_lines(file)?;
-
-        for line in &lines {
-            let (prefix, value): (&str, &str) = line.split_at(3);
-            match by_id(line.split_at(3).0.to_string()) {
-                Err(_err) => {
-                    println!("Warning invalid Element ID: '{prefix}' WITH VALUE: '{value}' please submit an issue to have the Element added.")
-                },
-                Ok(_) => {}
-            } 
+    fn from_lines<S: AsRef<str>>(lines: &[S], context: &mut ParseContext) -> IdentificationCard {
+        let date_format = context.date_format(lines);
+        let card_revision_date = context.date::<DHSCardRevisionDate, S>(lines, date_format);
+
+        let identification_card = IdentificationCard {
+            document_expiration_date:           context.date::<DocumentExpirationDate, S>(lines, date_format),
+            customer_family_name:               context.text::<CustomerFamilyName, S>(lines),
+            customer_first_name:                context.text::<CustomerFirstName, S>(lines),
+            document_issue_date:                context.date::<DocumentIssueDate, S>(lines, date_format),
+            customer_middle_names:              context.text::<CustomerMiddleNames, S>(lines),
+            date_of_birth:                      context.date::<DateOfBirth, S>(lines, date_format),
+            physical_description_sex:           context.code::<PhysicalDescriptionSex, _, _>(lines),
+            physical_description_eye_color:     context.code::<PhysicalDescriptionEyeColor, _, _>(lines),
+            physical_description_height:        context.parse::<PhysicalDescriptionHeight, _, _>(lines),
+            address_street_1:                   context.text::<AddressStreet1, S>(lines),
+            address_city:                       context.text::<AddressCity, S>(lines),
+            address_jurisdiction_code:          context.text::<AddressJurisdictionCode, S>(lines),
+            address_postal_code:                context.value::<AddressPostalCode, _>(lines).and_then(|value| PostalCode::parse(&value)),
+            customer_id_number:                 context.text::<CustomerIdNumber, S>(lines),
+            document_descriminator:             context.text::<DocumentDescriminator, S>(lines),
+            country_identification:             context.text::<CountryIdentification, S>(lines),
+            family_name_truncation:             context.truncation::<FamilyNameTruncation, _>(lines),
+            first_name_truncation:              context.truncation::<FirstNameTruncation, _>(lines),
+            middle_name_truncation:             context.truncation::<MiddleNameTruncation, _>(lines),
+            address_street_2:                   context.text::<AddressStreet2, S>(lines),
+            hair_color:                         context.code::<HairColor, _, _>(lines),
+            place_of_birth:                     context.text::<PlaceOfBirth, S>(lines),
+            audit_information:                  context.text::<AuditInformation, S>(lines),
+            inventory_control_number:           context.text::<InventoryControlNumber, S>(lines),
+            alias_family_name:                  context.text::<AliasFamilyName, S>(lines),
+            alias_given_name:                   context.text::<AliasGivenName, S>(lines),
+            alias_suffix_name:                  context.text::<AliasSuffixName, S>(lines),
+            name_suffix:                        context.text::<NameSuffix, S>(lines),
+            weight:                             context.weight(lines),
+            race_ethnicity:                     context.code::<RaceEthnicity, _, _>(lines),
+            compliance:                         context.compliance(lines, card_revision_date),
+            card_revision_date,
+            under_18_until:                     context.date::<Under18Until, S>(lines, date_format),
+            under_19_until:                     context.date::<Under19Until, S>(lines, date_format),
+            under_21_until:                     context.date::<Under21Until, S>(lines, date_format),
+            organ_donor_indicator:              context.text::<OrganDonorIndicator, S>(lines),
+            veteran_indicator:                  context.text::<VeteranIndicator, S>(lines),
+            issuing_jurisdiction:               context.issuing_jurisdiction(lines),
+            unknown_elements:                   context.unknown_elements(lines),
+            duplicate_elements:                 context.duplicate_elements(lines)
+        };
+
+        for mismatch in identification_card.age_threshold_mismatches() {
+            context.report(None, mismatch);
         }
 
-        Ok((header, IdentificationCard {
-            document_expiration_date:           DocumentExpirationDate::no_error_find_in_lines(&lines),
-            customer_family_name:               CustomerFamilyName::no_error_find_in_lines(&lines),
-            customer_first_name:                CustomerFirstName::no_error_find_in_lines(&lines),
-            document_issue_date:                DocumentIssueDate::no_error_find_in_lines(&lines),
-            customer_middle_names:              CustomerMiddleNames::no_error_find_in_lines(&lines),
-            date_of_birth:                      DateOfBirth::no_error_find_in_lines(&lines),
-            physical_description_sex:           PhysicalDescriptionSex::no_error_find_in_lines(&lines),
-            physical_description_eye_color:     PhysicalDescriptionEyeColor::no_error_find_in_lines(&lines),
-            physical_description_height:        PhysicalDescriptionHeight::no_error_find_in_lines(&lines),
-            address_street_1:                   AddressStreet1::no_error_find_in_lines(&lines),
-            address_city:                       AddressCity::no_error_find_in_lines(&lines),
-            address_jurisdiction_code:          AddressJurisdictionCode::no_error_find_in_lines(&lines),
-            address_postal_code:                AddressPostalCode::no_error_find_in_lines(&lines),
-            customer_id_number:                 CustomerIdNumber::no_error_find_in_lines(&lines),
-            document_descriminator:             DocumentDescriminator::no_error_find_in_lines(&lines),
-            country_identification:             CountryIdentification::no_error_find_in_lines(&lines),
-            family_name_truncation:             FamilyNameTruncation::no_error_find_in_lines(&lines),
-            first_name_truncation:              FirstNameTruncation::no_error_find_in_lines(&lines),
-            middle_name_truncation:             MiddleNameTruncation::no_error_find_in_lines(&lines),
-            address_street_2:                   AddressStreet2::no_error_find_in_lines(&lines),
-            hair_color:                         HairColor::no_error_find_in_lines(&lines),
-            place_of_birth:                     PlaceOfBirth::no_error_find_in_lines(&lines),
-            audit_information:                  AuditInformation::no_error_find_in_lines(&lines),
-            inventory_control_number:           InventoryControlNumber::no_error_find_in_lines(&lines),
-            alias_family_name:                  AliasFamilyName::no_error_find_in_lines(&lines),
-            alias_given_name:                   AliasGivenName::no_error_find_in_lines(&lines),
-            alias_suffix_name:                  AliasSuffixName::no_error_find_in_lines(&lines),
-            name_suffix:                        NameSuffix::no_error_find_in_lines(&lines),
-            physical_description_weight_range:  PhysicalDescriptionWeightRange::no_error_find_in_lines(&lines),
-            race_ethnicity:                     RaceEthnicity::no_error_find_in_lines(&lines),
-            compliance_type:                    DHSComplianceType::no_error_find_in_lines(&lines),
-            card_revision_date:                 DHSCardRevisionDate::no_error_find_in_lines(&lines),
-            limited_duration_document_indicator:LimitedDurationDocumentIndicator::no_error_find_in_lines(&lines),
-            weight_pounds:                      WeightPounds::no_error_find_in_lines(&lines),
-            weight_kilograms:                   WeightKilograms::no_error_find_in_lines(&lines),
-            under_18_until:                     Under18Until::no_error_find_in_lines(&lines),
-            under_19_until:                     Under19Until::no_error_find_in_lines(&lines),
-            under_21_until:                     Under21Until::no_error_find_in_lines(&lines),
-            organ_donor_indicator:              OrganDonorIndicator::no_error_find_in_lines(&lines),
-            veteran_indicator:                  VeteranIndicator::no_error_find_in_lines(&lines),
-        }))
-    }
-
-    
-}
\ No newline at end of file
+        identification_card
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    use crate::clock::{Clock, FixedClock};
+    use crate::codes::{Endorsement, EyeColor, HairColor, Restriction, Sex, VehicleClass};
+    use crate::diagnostics::Severity;
+    use crate::documents::{DriversLicense, IdentificationCard, ParseContext, Parsed, Reader};
+    use crate::encoding::Decoding;
+    use crate::error::Error;
+    use crate::header::SubfileType;
+    use crate::jurisdiction::Jurisdiction;
+    use crate::fixtures::{build_file, CALIFORNIA_V09, DRIVERS_LICENSE};
+    use crate::name::Truncation;
+    use crate::options::{DuplicateResolution, ParserOptions, Policy};
+    use crate::postal::PostalCode;
+    use crate::validity::Expiry;
+
+    fn codes<T>(parsed: &Parsed<T>) -> Vec<&'static str> {
+        parsed.diagnostics.iter().map(|diagnostic| diagnostic.code).collect()
+    }
+
+    #[test]
+    fn test_subfiles_are_sliced_by_offset() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE), ("ZC", &["ZCAVALUE", "ZCBOTHER"])]);
+
+        let (header, subfiles) = DriversLicense::get_header_and_subfiles(file.clone()).unwrap();
+        assert_eq!(header.subfile_designators().len(), 2);
+        assert_eq!(subfiles[0].subfile_type(), SubfileType::DriversLicense);
+        assert_eq!(subfiles[0].lines.len(), DRIVERS_LICENSE.len());
+        assert_eq!(subfiles[1].subfile_type(), SubfileType::Jurisdiction('C'));
+        assert_eq!(subfiles[1].lines, vec!["ZCAVALUE", "ZCBOTHER"]);
+
+        let (_, drivers_license) = DriversLicense::new(file, &ParserOptions::default()).unwrap();
+        assert_eq!(drivers_license.customer_id_number.as_deref(), Some("D1234562"));
+        assert_eq!(drivers_license.physical_description_height.map(|height| height.to_centimeters()), Some(175));
+        assert_eq!(drivers_license.address_postal_code.map(|postal_code| postal_code.to_string()).as_deref(), Some("95818"));
+    }
+
+    #[test]
+    fn test_jurisdiction_subfiles_are_kept_separate() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE), ("ZC", &["ZCAVALUE", "ZCBOTHER"])]);
+
+        let parsed = DriversLicense::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.customer_family_name.as_deref(), Some("SAMPLE"));
+        assert_eq!(parsed.jurisdiction_subfiles.len(), 1);
+        assert!(parsed.diagnostics.is_empty());
+
+        let zc = &parsed.jurisdiction_subfiles[0];
+        assert_eq!(zc.designator.subfile_type(), SubfileType::Jurisdiction('C'));
+        assert_eq!(zc.issuer_identification_number, 636014);
+        assert_eq!(zc.get("ZCB"), Some("OTHER"));
+        assert_eq!(zc.elements().collect::<Vec<_>>(), vec![("ZCA", "VALUE"), ("ZCB", "OTHER")]);
+    }
+
+    #[test]
+    fn test_latin1_bytes() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DCSTREMBLAY", "DACHÉLÈNE", "DCGCAN"])]);
+        let bytes: Vec<u8> = file.chars().map(|character| character as u8).collect();
+
+        let parsed = DriversLicense::read_bytes(&bytes, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.decoding, Decoding::Latin1);
+        assert_eq!(parsed.document.customer_first_name.as_deref(), Some("HÉLÈNE"));
+        assert_eq!(parsed.document.country_identification.as_deref(), Some("CAN"));
+        assert_eq!(parsed.decoded, file);
+
+        let mojibake = file.chars()
+            .map(|character| match character {
+                'É' => "Ã‰".to_string(),
+                'È' => "Ãˆ".to_string(),
+                _ => character.to_string()
+            })
+            .collect::<String>();
+
+        let parsed = DriversLicense::read(mojibake, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.decoding, Decoding::RepairedMojibake);
+        assert_eq!(parsed.document.customer_first_name.as_deref(), Some("HÉLÈNE"));
+        assert_eq!(parsed.decoded, file);
+
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DACHÉLÈNE", "DXXÉ"])]);
+        let bytes: Vec<u8> = file.chars().map(|character| character as u8).collect();
+
+        let parsed = DriversLicense::read_bytes(&bytes, &ParserOptions::default()).unwrap();
+        let span = parsed.diagnostics[0].span.clone().unwrap();
+        assert_eq!(&parsed.decoded[span], "DXXÉ");
+    }
+
+    #[test]
+    fn test_violations_are_reported() {
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DBB0831197A", "DCAC"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.date_of_birth, None);
+        assert_eq!(codes(&parsed), ["invalid-character", "document-type-invalid"]);
+        assert!(parsed.has_errors());
+    }
+
+    #[test]
+    fn test_unknown_elements_are_diagnostics() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DXXSECRET", "DC"])]);
+
+        let parsed = DriversLicense::read(file.clone(), &ParserOptions::default()).unwrap();
+        assert_eq!(codes(&parsed), ["unknown-element", "unknown-element"]);
+        assert_eq!(parsed.diagnostics[1].element_id.as_deref(), Some("DC"));
+
+        let diagnostic = &parsed.diagnostics[0];
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(diagnostic.element_id.as_deref(), Some("DXX"));
+        assert_eq!(&file[diagnostic.span.clone().unwrap()], "DXXSECRET");
+        assert!(!diagnostic.message.contains("SECRET"));
+
+        let dated = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DBB02301977"])]);
+        assert!(!DriversLicense::read(dated.clone(), &ParserOptions::default()).unwrap().diagnostics[0].message.contains("02301977"));
+
+        let parsed = DriversLicense::read(dated, &ParserOptions::default().include_values(true)).unwrap();
+        assert!(parsed.diagnostics[0].message.contains("02301977"));
+
+        let mut sink = Vec::new();
+        parsed.report_to(&mut sink);
+        assert_eq!(sink, parsed.diagnostics);
+
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DAéSECRETVALUE", "DAéSECRETVALUE"])]);
+
+        let parsed = DriversLicense::read(file, &ParserOptions::default()).unwrap();
+        assert!(parsed.diagnostics.iter().all(|diagnostic| diagnostic.element_id.as_deref() == Some("DAé")));
+        assert!(parsed.diagnostics.iter().all(|diagnostic| !diagnostic.message.contains("SECRET")));
+    }
+
+    #[test]
+    fn test_unknown_elements_are_kept() {
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DXXFIRST", "DCSSAMPLE", "DXYSECOND "])]);
+
+        let parsed = IdentificationCard::read(file.clone(), &ParserOptions::lenient()).unwrap();
+        assert!(parsed.diagnostics.is_empty());
+
+        let unknown_elements = &parsed.document.unknown_elements;
+        assert_eq!(unknown_elements.iter().map(|element| (element.id.as_str(), element.value.as_str(), element.position)).collect::<Vec<_>>(),
+            vec![("DXX", "FIRST", 1), ("DXY", "SECOND ", 3)]);
+        assert_eq!(&file[unknown_elements[1].span.clone().unwrap()], "DXYSECOND ");
+
+        let identification_card = IdentificationCard::from_lines(&["DAQD1234562", "DXXFIRST"], &mut ParseContext::default());
+        assert_eq!(identification_card.unknown_elements[0].span, None);
+    }
+
+    #[test]
+    fn test_version_01_card() {
+        let file = build_file("AAMVA63600001", &[("DL", &["DAAPUBLIC,JOHN,Q", "DAQ0123456789", "DARD", "DBGMED", "DAV180"])]);
+
+        let (_, drivers_license) = DriversLicense::new(file, &ParserOptions::default()).unwrap();
+        assert_eq!(drivers_license.customer_family_name.as_deref(), Some("PUBLIC"));
+        assert_eq!(drivers_license.customer_first_name.as_deref(), Some("JOHN"));
+        assert_eq!(drivers_license.jurisdiction_specific_vehicle_class.unwrap().codes, vec![VehicleClass::Other("D".to_string())]);
+        assert_eq!(drivers_license.alias_given_name, None);
+        assert_eq!(drivers_license.physical_description_height.map(|height| height.to_string()).as_deref(), Some("180 CM"));
+    }
+
+    #[test]
+    fn test_unknown_versions() {
+        let file = build_file("ANSI 6360141100", &[("DL", &["DAQD1234562", "DCSSAMPLE"])]);
+        let parsed = DriversLicense::read(file.clone(), &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.customer_family_name.as_deref(), Some("SAMPLE"));
+        assert_eq!(parsed.header.aamva_version_number(), 11);
+        assert_eq!(codes(&parsed), ["unknown-version"]);
+        assert!(!parsed.has_errors());
+
+        assert!(DriversLicense::new(file.clone(), &ParserOptions::default()).is_ok());
+        assert!(DriversLicense::read(file.clone(), &ParserOptions::lenient()).unwrap().diagnostics.is_empty());
+        assert!(matches!(DriversLicense::read(file, &ParserOptions::strict()), Err(Error::UnknownVersion { aamva_version: 11, .. })));
+    }
+
+    #[test]
+    fn test_dates() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE)]);
+
+        let (_, drivers_license) = DriversLicense::new(file, &ParserOptions::default()).unwrap();
+        assert_eq!(drivers_license.date_of_birth, NaiveDate::from_ymd_opt(1977, 8, 31));
+        assert_eq!(drivers_license.document_expiration_date, NaiveDate::from_ymd_opt(2030, 8, 31));
+
+        let file = build_file("ANSI 6360120900", &[("ID", &["DAQ123456789", "DBB19770831", "DBA99999999", "DBD20200230"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.date_of_birth, NaiveDate::from_ymd_opt(1977, 8, 31));
+        assert_eq!(parsed.document.document_expiration_date, None);
+        assert_eq!(parsed.document.document_issue_date, None);
+        assert_eq!(codes(&parsed), ["unknown-date", "invalid-date"]);
+    }
+
+    #[test]
+    fn test_codes() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE)]);
+
+        let parsed = DriversLicense::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.physical_description_sex, Some(Sex::Male));
+        assert_eq!(parsed.document.physical_description_eye_color, Some(EyeColor::Brown));
+        assert!(parsed.diagnostics.is_empty());
+
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DBCF", "DAYBROWN", "DAZPURPLE"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.physical_description_sex, Some(Sex::Female));
+        assert_eq!(parsed.document.physical_description_eye_color, Some(EyeColor::Brown));
+        assert_eq!(parsed.document.hair_color, Some(HairColor::Other("PURPLE".to_string())));
+        assert_eq!(codes(&parsed), ["unknown-code"]);
+        assert_eq!(parsed.diagnostics[0].severity, Severity::Warning);
+
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DAYPURPLE"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.physical_description_eye_color, Some(EyeColor::Other("PURPLE".to_string())));
+        assert_eq!(codes(&parsed), ["invalid-length", "unknown-code"]);
+    }
+
+    #[test]
+    fn test_weight() {
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DAX068"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        let weight = parsed.document.weight.unwrap();
+        assert_eq!(weight.to_pounds(), Some(150));
+        assert_eq!(weight.range().map(|range| range.code()), Some(3));
+        assert!(parsed.diagnostics.is_empty());
+
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DAW150", "DCE7"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.weight.and_then(|weight| weight.to_kilograms()), Some(68));
+        assert_eq!(codes(&parsed), ["weight-mismatch"]);
+        assert_eq!(parsed.diagnostics[0].element_id.as_deref(), Some("DCE"));
+    }
+
+    #[test]
+    fn test_postal_code() {
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DAK95818-1234"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.address_postal_code, Some(PostalCode::ZipPlus4("95818".to_string(), "1234".to_string())));
+        assert!(parsed.diagnostics.is_empty());
+
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DAK958181234000"])]);
+
+        let parsed = IdentificationCard::read(file.clone(), &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.address_postal_code, None);
+        assert_eq!(codes(&parsed), ["invalid-length"]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::lenient()).unwrap();
+        assert_eq!(parsed.document.address_postal_code, Some(PostalCode::Other("958181234000".to_string())));
+    }
+
+    #[test]
+    fn test_truncated_names() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DCSWOLFESCHLEGELSTEINHAUSENBERGERDORFFSCHMI", "DACJOHN", "DDET", "DDFT", "DDGN"])]);
+
+        let parsed = DriversLicense::read(file.clone(), &ParserOptions::default()).unwrap();
+        let name = parsed.document.name();
+        assert!(name.is_truncated());
+        assert_eq!(name.family.as_ref().map(|family| family.truncation), Some(Truncation::Truncated));
+        assert!(name.family.unwrap().matches("Wolfeschlegelsteinhausenbergerdorffschmidt"));
+        assert_eq!(name.middle, None);
+        assert_eq!(codes(&parsed), ["truncated-name-too-short"]);
+        assert_eq!(parsed.diagnostics[0].element_id.as_deref(), Some("DAC"));
+        assert_eq!(parsed.document.customer_first_name, None);
+
+        let parsed = DriversLicense::read(file, &ParserOptions::lenient()).unwrap();
+        assert_eq!(parsed.document.customer_first_name.as_deref(), Some("JOHN"));
+        assert!(!parsed.has_errors());
+    }
+
+    #[test]
+    fn test_compliance() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DDAF", "DDB04022018", "DDD1"])]);
+
+        let (_, drivers_license) = DriversLicense::new(file, &ParserOptions::default()).unwrap();
+        assert_eq!(drivers_license.compliance.is_real_id_compliant(), Some(true));
+        assert_eq!(drivers_license.compliance.is_limited_duration(), Some(true));
+        assert_eq!(drivers_license.compliance.card_revision_date, NaiveDate::from_ymd_opt(2018, 4, 2));
+
+        let file = build_file("ANSI 6360140501", &[("DL", &["DAQD1234562", "DDAN"])]);
+
+        let (_, drivers_license) = DriversLicense::new(file, &ParserOptions::default()).unwrap();
+        assert!(drivers_license.compliance.predates_2013_standard());
+        assert_eq!(drivers_license.compliance.is_real_id_compliant(), None);
+    }
+
+    #[test]
+    fn test_privilege_codes() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DCAC1", "DCBB J", "DCDH", "DCMC", "DCNHT", "DCONONE", "DCRCORRECTIVE LENSES, OTHER"])]);
+
+        let parsed = DriversLicense::read(file, &ParserOptions::default()).unwrap();
+        let drivers_license = parsed.document;
+        let restrictions = drivers_license.jurisdiction_specific_restriction_codes.unwrap();
+        assert_eq!(restrictions.codes, vec![Restriction::Other("B".to_string()), Restriction::Other("J".to_string())]);
+        assert_eq!(restrictions.describe(), "CORRECTIVE LENSES, OTHER");
+        assert_eq!(drivers_license.jurisdiction_specific_vehicle_class.unwrap().codes, vec![VehicleClass::Other("C1".to_string())]);
+        assert_eq!(drivers_license.standard_vehicle_classification.unwrap().codes, vec![VehicleClass::C]);
+        assert_eq!(drivers_license.standard_endorsement_code.unwrap().codes, vec![Endorsement::HazardousMaterials, Endorsement::DoubleTripleTrailers]);
+        assert_eq!(drivers_license.standard_restriction_code.unwrap().codes, vec![]);
+        assert_eq!(drivers_license.jurisdiction_specific_endorsement_codes.unwrap().description, None);
+        assert!(parsed.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_age() {
+        let file = build_file(CALIFORNIA_V09, &[("ID", &["DAQD1234562", "DBB02292004", "DDH03012022", "DDI02282023", "DDJ02272025"])]);
+
+        let parsed = IdentificationCard::read(file, &ParserOptions::default()).unwrap();
+        let clock = FixedClock::on(NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+        assert_eq!(parsed.document.age(&clock), Some(20));
+        assert_eq!(parsed.document.is_at_least(21, clock.today()), Some(false));
+        assert_eq!(parsed.document.becomes_of_age(21), NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(codes(&parsed), ["age-threshold-mismatch"]);
+        assert_eq!(parsed.diagnostics[0].element_id.as_deref(), Some("DDJ"));
+    }
+
+    #[test]
+    fn test_expiry() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE)]);
+
+        let (_, drivers_license) = DriversLicense::new(file, &ParserOptions::default()).unwrap();
+        assert_eq!(drivers_license.issuing_jurisdiction, Some(Jurisdiction::California));
+
+        let clock = FixedClock(Utc.with_ymd_and_hms(2030, 9, 1, 6, 59, 59).unwrap());
+        assert_eq!(drivers_license.is_expired(&clock, None).is_expired(), Some(false));
+        assert_eq!(drivers_license.days_until_expiry(&clock), Ok(0));
+
+        let clock = FixedClock(Utc.with_ymd_and_hms(2030, 9, 1, 7, 0, 0).unwrap());
+        assert!(matches!(drivers_license.is_expired(&clock, None), Expiry::Expired { .. }));
+        assert!(matches!(drivers_license.is_expired(&clock, Some(Duration::days(1))), Expiry::InGracePeriod { .. }));
+
+        let clock = FixedClock::on(NaiveDate::from_ymd_opt(2020, 8, 30).unwrap());
+        assert_eq!(drivers_license.is_not_yet_valid(&clock).is_not_yet_valid(), Some(true));
+
+        // Without a known IIN the jurisdiction comes from DAJ.
+        let file = build_file("ANSI 6369990900", &[("ID", &["DAQD1234562", "DAJON", "DCGCAN", "DBA20300831"])]);
+
+        let (_, identification_card) = IdentificationCard::new(file, &ParserOptions::default()).unwrap();
+        assert_eq!(identification_card.issuing_jurisdiction, Some(Jurisdiction::Ontario));
+        assert_eq!(identification_card.is_expired(&clock, None).is_expired(), Some(false));
+    }
+
+    #[test]
+    fn test_mismatched_offset_is_rejected() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE), ("ZC", &["ZCAVALUE"])]).replacen("DL0041", "DL0042", 1);
+
+        assert!(matches!(DriversLicense::new(file, &ParserOptions::default()), Err(Error::SubfileTypeMismatch { offset: 42, .. })));
+    }
+
+    #[test]
+    fn test_mismatched_subfile_type_is_rejected() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE), ("ZC", &["ZCAVALUE"])]).replacen("ZC", "ZV", 1);
+
+        assert!(matches!(DriversLicense::new(file, &ParserOptions::default()), Err(Error::SubfileTypeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_strict_options() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE)]);
+        assert!(DriversLicense::read(file, &ParserOptions::strict()).is_ok());
+
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DXXSECRET"])]);
+        let error = DriversLicense::read(file.clone(), &ParserOptions::strict()).unwrap_err();
+        assert!(matches!(error, Error::Rejected { diagnostic } if diagnostic.code == "unknown-element"));
+
+        let options = ParserOptions::strict().unknown_elements(Policy::Ignore);
+        assert!(DriversLicense::read(file, &options).unwrap().diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_lenient_options() {
+        let family_name = "A".repeat(41);
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", &format!("DCS{family_name}"), "DACJOHN", "DACJANE"])]);
+
+        let parsed = DriversLicense::read(file.clone(), &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.customer_family_name, None);
+        assert_eq!(codes(&parsed), ["conflicting-element", "invalid-length"]);
+
+        let parsed = DriversLicense::read(file, &ParserOptions::lenient()).unwrap();
+        assert_eq!(parsed.document.customer_family_name, Some(family_name));
+        assert_eq!(parsed.document.customer_first_name.as_deref(), Some("JOHN"));
+        assert!(!parsed.has_errors());
+    }
+
+    #[test]
+    fn test_duplicate_elements() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", &["DAQD1234562", "DBB08311977", "DAQD7654321", "DBB08311977"])]);
+
+        let parsed = DriversLicense::read(file.clone(), &ParserOptions::default()).unwrap();
+        assert_eq!(parsed.document.customer_id_number.as_deref(), Some("D1234562"));
+        assert_eq!(codes(&parsed), ["conflicting-element", "duplicate-element"]);
+        assert_eq!(parsed.diagnostics[0].severity, Severity::Error);
+        assert_eq!(parsed.diagnostics[1].severity, Severity::Warning);
+
+        let duplicate = &parsed.document.duplicate_elements[0];
+        assert_eq!(duplicate.id, "DAQ");
+        assert_eq!(duplicate.occurrences.iter().map(|occurrence| occurrence.value.as_str()).collect::<Vec<_>>(), vec!["D1234562", "D7654321"]);
+        assert_eq!(&file[duplicate.occurrences[1].span.clone().unwrap()], "DAQD7654321");
+
+        let options = ParserOptions::default().duplicate_resolution(DuplicateResolution::LastWins);
+        let parsed = DriversLicense::read(file.clone(), &options).unwrap();
+        assert_eq!(parsed.document.customer_id_number.as_deref(), Some("D7654321"));
+        assert_eq!(parsed.document.duplicate_elements.len(), 2);
+
+        let error = DriversLicense::read(file, &ParserOptions::default().duplicate_elements(Policy::Reject)).unwrap_err();
+        assert!(matches!(error, Error::Rejected { diagnostic } if diagnostic.element_id.as_deref() == Some("DAQ")));
+    }
+
+    #[test]
+    fn test_separator_quirks_and_broken_directories() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE)]).replacen("@\n\x1e\r", "]L0@\r\n\x1e\r", 1);
+        assert!(matches!(DriversLicense::new(file.clone(), &ParserOptions::default()), Err(Error::NonCompliance)));
+
+        let parsed = DriversLicense::read(file, &ParserOptions::lenient()).unwrap();
+        assert_eq!(parsed.document.customer_family_name.as_deref(), Some("SAMPLE"));
+        assert_eq!(parsed.header.issuer_identification_number(), 636014);
+        assert_eq!(codes(&parsed), ["unexpected-separators", "invalid-subfile-designator"]);
+
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE)]).replacen("ANSI 636014", "ANSI 63601X", 1);
+        let options = ParserOptions::default().header_fields(Policy::Report);
+
+        let parsed = DriversLicense::read(file, &options).unwrap();
+        assert_eq!(parsed.document.customer_id_number.as_deref(), Some("D1234562"));
+        assert_eq!(parsed.header.aamva_version_number(), 9);
+        assert_eq!(codes(&parsed), ["invalid-header"]);
+    }
+}
@@ -1,6 +1,9 @@
+use std::ops::Range;
+
 use lazy_static::lazy_static;
 
 use crate::error::Error;
+use crate::versions::LATEST_VERSION;
 
 #[derive(Debug)]
 pub struct ElementValidationData {
@@ -13,7 +16,47 @@ pub struct ElementValidationData {
     pub document_type:  &'static str
 }
 
+impl ElementValidationData {
+
+    /// Checks a value against the character classes and length declared for the element. Trailing spaces are
+    /// treated as padding, so they count towards a fixed length but not against the character classes.
+    pub fn check(&self, value: &str) -> Result<(), Error> {
+        let unpadded = value.trim_end_matches(' ');
+
+        for (position, character) in unpadded.chars().enumerate() {
+            let allowed = match character {
+                c if c.is_ascii_digit() => *self.numeric,
+                c if c.is_alphabetic() => *self.alpha,
+                _ => *self.special
+            };
+
+            if !allowed {
+                return Err(Error::InvalidCharacter { element_id: self.id.to_string(), character, position });
+            }
+        }
+
+        let length = *self.length as usize;
+
+        match *self.fixed_length {
+            true if value.chars().count() != length => Err(Error::InvalidStringLength {
+                element_id: self.id.to_string(),
+                found: value.chars().count(),
+                expected: format!("exactly {length}")
+            }),
+            false if unpadded.chars().count() > length => Err(Error::InvalidStringLength {
+                element_id: self.id.to_string(),
+                found: unpadded.chars().count(),
+                expected: format!("at most {length}")
+            }),
+            _ => Ok(())
+        }
+    }
 
+    /// Whether the element may appear on the given document type, "DL" or "ID".
+    pub fn applies_to(&self, document_type: &str) -> bool {
+        self.document_type == "Both" || self.document_type == document_type
+    }
+}
 
 pub trait Element {
     fn data() -> ElementValidationData;
@@ -24,7 +67,7 @@ pub trait Element {
         let mut new_values: Vec<&str> = vec![];
 
         for val in split_values {
-            if val != "" && val != "\r" {
+            if !val.is_empty() && val != "\r" {
                 new_values.push(val);
             }
         }
@@ -32,40 +75,43 @@ pub trait Element {
         new_values.join("")
     }
 
-    fn no_error_validate(element_line: &String) -> Option<String> {
-        match Self::validate(element_line) {
-            Ok(value) => Some(value),
-            Err(_err) => None
-        }
+    fn no_error_validate(element_line: &str) -> Option<String> {
+        Self::validate(element_line).ok()
     }
 
-    fn validate(element_line: &String) -> Result<String, Error> {
+    fn validate(element_line: &str) -> Result<String, Error> {
         // Here is where we will validate that the data matches AAVMA specification.
         let data = Self::data();
 
-        let (prefix, value) = element_line.split_at(3);
-        
+        let (prefix, value) = split_element_line(element_line);
 
         if prefix != data.id {
-            Err(Error::MatchError { var_1: prefix.to_string(), var_2: data.id.to_string() })
-        } else { 
-            Ok(value.to_string())
+            return Err(Error::MatchError { var_1: prefix.to_string(), var_2: data.id.to_string() });
         }
+
+        data.check(value)?;
+
+        Ok(value.to_string())
     }
 
-    fn find_in_lines(lines: &Vec<String>) -> Result<String, Error> {
+    fn find_in_lines<S: AsRef<str>>(lines: &[S]) -> Result<String, Error> {
         for line in lines {
-            match Self::validate(line) {
-                Ok(value) => {
-                    return Ok(value);
-                }, 
-                Err(_) => {} 
+            match Self::validate(line.as_ref()) {
+                Err(Error::MatchError { .. }) => {},
+                result => return result
             }
         }
-        return  Err(Error::ElementNotFound { element_name: Self::data().id.to_string() });
+        Err(Error::ElementNotFound { element_name: Self::data().id.to_string() })
     }
 
-    fn no_error_find_in_lines(lines: &Vec<String>) -> Option<String> {
+    /// The value of the element as it was encoded, without validating it.
+    fn find_unchecked_in_lines<S: AsRef<str>>(lines: &[S]) -> Option<String> {
+        let id = Self::data().id;
+
+        lines.iter().find_map(|line| line.as_ref().strip_prefix(id)).map(|value| value.to_string())
+    }
+
+    fn no_error_find_in_lines<S: AsRef<str>>(lines: &[S]) -> Option<String> {
         match Self::find_in_lines(lines) {
             Ok(value)  => {
                 Some(Self::remove_padding(value))
@@ -153,7 +199,8 @@ pub struct PhysicalDescriptionSex;
 
 impl Element for PhysicalDescriptionSex {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DBC", alpha: &false, numeric: &true, special: &false, fixed_length: &true, length: &1, document_type: "Both"}
+        // Legacy cards write "M" and "F" in place of the numeric codes.
+        ElementValidationData { id: "DBC", alpha: &true, numeric: &true, special: &false, fixed_length: &true, length: &1, document_type: "Both"}
     }
 }
 
@@ -169,7 +216,7 @@ pub struct PhysicalDescriptionHeight;
 
 impl Element for PhysicalDescriptionHeight {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DAU", alpha: &true, numeric: &true, special: &true, fixed_length: &true, length: &6, document_type: "Both" }
+        ElementValidationData { id: "DAU", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &6, document_type: "Both" }
     }
 }
 
@@ -201,7 +248,8 @@ pub struct AddressPostalCode;
 
 impl Element for AddressPostalCode {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DAK", alpha: &true, numeric: &true, special: &true, fixed_length: &true, length: &11, document_type: "Both" }
+        // Fixed at 11 by the standard, but most jurisdictions write five or nine digits, often with a hyphen and no padding.
+        ElementValidationData { id: "DAK", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &11, document_type: "Both" }
     }
 }
 
@@ -347,7 +395,8 @@ pub struct StandardVehicleClassification;
 
 impl Element for StandardVehicleClassification {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DCM", alpha: &true, numeric: &true, special: &false, fixed_length: &true, length: &4, document_type: "DL" }
+        // DCM, DCN and DCO are fixed by the standard, but cards rarely pad them, e.g. "DCMC" or "DCONONE".
+        ElementValidationData { id: "DCM", alpha: &true, numeric: &true, special: &false, fixed_length: &false, length: &4, document_type: "DL" }
     }
 }
 
@@ -355,14 +404,14 @@ pub struct StandardEndorsementCode;
 
 impl Element for StandardEndorsementCode {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DCN", alpha: &true, numeric: &true, special: &false, fixed_length: &true, length: &5, document_type: "DL" }
+        ElementValidationData { id: "DCN", alpha: &true, numeric: &true, special: &false, fixed_length: &false, length: &5, document_type: "DL" }
     }
 }
 pub struct StandardRestrictionCode;
 
 impl Element for StandardRestrictionCode {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DCO", alpha: &true, numeric: &true, special: &false, fixed_length: &true, length: &12, document_type: "DL" }
+        ElementValidationData { id: "DCO", alpha: &true, numeric: &true, special: &false, fixed_length: &false, length: &12, document_type: "DL" }
     }
 }
 
@@ -427,14 +476,15 @@ pub struct WeightPounds;
 
 impl Element for WeightPounds {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DAW", alpha: &false, numeric: &true, special: &false, fixed_length: &true, length: &3, document_type: "Both" }
+        // Weights are written without leading zeros as often as with them, e.g. "DAX68".
+        ElementValidationData { id: "DAW", alpha: &false, numeric: &true, special: &false, fixed_length: &false, length: &3, document_type: "Both" }
     }
 }
 pub struct WeightKilograms;
 
 impl Element for WeightKilograms {
     fn data() -> ElementValidationData {
-        ElementValidationData { id: "DAX", alpha: &false, numeric: &true, special: &false, fixed_length: &true, length: &3, document_type: "Both" }
+        ElementValidationData { id: "DAX", alpha: &false, numeric: &true, special: &false, fixed_length: &false, length: &3, document_type: "Both" }
     }
 }
 pub struct Under18Until;
@@ -478,64 +528,408 @@ impl Element for VeteranIndicator {
     }
 }
 
+// Elements below were defined by AAMVA versions 01 through 03 and later replaced or removed, see `VERSION_01_ELEMENTS`
+// and `VERSION_02_ELEMENTS`.
+
+pub struct DriverLicenseName;
+
+impl Element for DriverLicenseName {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAA", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct DriverLastName;
+
+impl Element for DriverLastName {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAB", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct DriverNameSuffix;
+
+impl Element for DriverNameSuffix {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAE", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &5, document_type: "Both" }
+    }
+}
+
+pub struct DriverNamePrefix;
+
+impl Element for DriverNamePrefix {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAF", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &5, document_type: "Both" }
+    }
+}
+
+pub struct ResidenceStreetAddress1;
+
+impl Element for ResidenceStreetAddress1 {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAL", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct ResidenceStreetAddress2;
+
+impl Element for ResidenceStreetAddress2 {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAM", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct ResidenceCity;
+
+impl Element for ResidenceCity {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAN", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &20, document_type: "Both" }
+    }
+}
+
+pub struct ResidenceJurisdictionCode;
+
+impl Element for ResidenceJurisdictionCode {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAO", alpha: &true, numeric: &false, special: &false, fixed_length: &true, length: &2, document_type: "Both" }
+    }
+}
+
+pub struct ResidencePostalCode;
+
+impl Element for ResidencePostalCode {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAP", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &11, document_type: "Both" }
+    }
+}
+
+pub struct LicenseClassificationCode;
+
+impl Element for LicenseClassificationCode {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAR", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &4, document_type: "DL" }
+    }
+}
+
+pub struct LicenseRestrictionCode;
+
+impl Element for LicenseRestrictionCode {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAS", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &10, document_type: "DL" }
+    }
+}
+
+pub struct LicenseEndorsementsCode;
+
+impl Element for LicenseEndorsementsCode {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAT", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &5, document_type: "DL" }
+    }
+}
+
+pub struct HeightCentimeters;
+
+impl Element for HeightCentimeters {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DAV", alpha: &false, numeric: &true, special: &false, fixed_length: &false, length: &3, document_type: "Both" }
+    }
+}
+
+pub struct IssueTimestamp;
+
+impl Element for IssueTimestamp {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBE", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &25, document_type: "Both" }
+    }
+}
+
+pub struct NumberOfDuplicates;
+
+impl Element for NumberOfDuplicates {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBF", alpha: &false, numeric: &true, special: &false, fixed_length: &false, length: &2, document_type: "Both" }
+    }
+}
+
+pub struct MedicalIndicatorCodes;
+
+impl Element for MedicalIndicatorCodes {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBG", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &5, document_type: "Both" }
+    }
+}
+
+pub struct OrganDonor;
+
+impl Element for OrganDonor {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBH", alpha: &true, numeric: &false, special: &false, fixed_length: &true, length: &1, document_type: "Both" }
+    }
+}
+
+pub struct NonResidentIndicator;
+
+impl Element for NonResidentIndicator {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBI", alpha: &true, numeric: &false, special: &false, fixed_length: &true, length: &1, document_type: "Both" }
+    }
+}
+
+pub struct UniqueCustomerIdentifier;
+
+impl Element for UniqueCustomerIdentifier {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBJ", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &23, document_type: "Both" }
+    }
+}
+
+pub struct SocialSecurityNumber;
+
+impl Element for SocialSecurityNumber {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBK", alpha: &false, numeric: &true, special: &false, fixed_length: &true, length: &9, document_type: "Both" }
+    }
+}
+
+pub struct AkaDateOfBirth;
+
+impl Element for AkaDateOfBirth {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBL", alpha: &false, numeric: &true, special: &false, fixed_length: &true, length: &8, document_type: "Both" }
+    }
+}
+
+pub struct AkaSocialSecurityNumber;
+
+impl Element for AkaSocialSecurityNumber {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBM", alpha: &false, numeric: &true, special: &false, fixed_length: &true, length: &9, document_type: "Both" }
+    }
+}
+
+pub struct AkaName;
+
+impl Element for AkaName {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBN", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct AkaLastName;
+
+impl Element for AkaLastName {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBO", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct AkaFirstName;
+
+impl Element for AkaFirstName {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBP", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct AkaMiddleName;
+
+impl Element for AkaMiddleName {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBQ", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &35, document_type: "Both" }
+    }
+}
+
+pub struct AkaSuffix;
+
+impl Element for AkaSuffix {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBR", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &5, document_type: "Both" }
+    }
+}
+
+pub struct AkaPrefix;
+
+impl Element for AkaPrefix {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DBS", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &5, document_type: "Both" }
+    }
+}
+
+pub struct CustomerGivenNames;
+
+impl Element for CustomerGivenNames {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DCT", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &80, document_type: "Both" }
+    }
+}
+
+pub struct FederalCommercialVehicleCodes;
+
+impl Element for FederalCommercialVehicleCodes {
+    fn data() -> ElementValidationData {
+        ElementValidationData { id: "DCH", alpha: &true, numeric: &true, special: &true, fixed_length: &false, length: &4, document_type: "DL" }
+    }
+}
+
 lazy_static!(
+    // AAMVA DL/ID-2000, the only version with the full name in DAA and last name in DAB.
+    static ref VERSION_01_ELEMENTS: Vec<ElementValidationData> = {
+        vec![
+            DriverLicenseName::data(),
+            DriverLastName::data(),
+            CustomerFirstName::data(),
+            CustomerMiddleNames::data(),
+            DriverNameSuffix::data(),
+            DriverNamePrefix::data(),
+            AddressStreet1::data(),
+            AddressStreet2::data(),
+            AddressCity::data(),
+            AddressJurisdictionCode::data(),
+            AddressPostalCode::data(),
+            ResidenceStreetAddress1::data(),
+            ResidenceStreetAddress2::data(),
+            ResidenceCity::data(),
+            ResidenceJurisdictionCode::data(),
+            ResidencePostalCode::data(),
+            CustomerIdNumber::data(),
+            LicenseClassificationCode::data(),
+            LicenseRestrictionCode::data(),
+            LicenseEndorsementsCode::data(),
+            PhysicalDescriptionHeight::data(),
+            HeightCentimeters::data(),
+            WeightPounds::data(),
+            WeightKilograms::data(),
+            PhysicalDescriptionEyeColor::data(),
+            HairColor::data(),
+            DocumentExpirationDate::data(),
+            DateOfBirth::data(),
+            PhysicalDescriptionSex::data(),
+            DocumentIssueDate::data(),
+            IssueTimestamp::data(),
+            NumberOfDuplicates::data(),
+            MedicalIndicatorCodes::data(),
+            OrganDonor::data(),
+            NonResidentIndicator::data(),
+            UniqueCustomerIdentifier::data(),
+            SocialSecurityNumber::data(),
+            AkaDateOfBirth::data(),
+            AkaSocialSecurityNumber::data(),
+            AkaName::data(),
+            AkaLastName::data(),
+            AkaFirstName::data(),
+            AkaMiddleName::data(),
+            AkaSuffix::data(),
+            AkaPrefix::data()
+        ]
+    };
+
+    // Versions 02 and 03, given names are combined in DCT.
+    static ref VERSION_02_ELEMENTS: Vec<ElementValidationData> = {
+        vec![
+            JurisdictionSpecificVehicleClass::data(),
+            JurisdictionSpecificRestrictionCodes::data(),
+            JurisdictionSpecificEndorsementCodes::data(),
+            DocumentExpirationDate::data(),
+            CustomerFamilyName::data(),
+            CustomerGivenNames::data(),
+            DocumentIssueDate::data(),
+            DateOfBirth::data(),
+            PhysicalDescriptionSex::data(),
+            PhysicalDescriptionEyeColor::data(),
+            PhysicalDescriptionHeight::data(),
+            AddressStreet1::data(),
+            AddressCity::data(),
+            AddressJurisdictionCode::data(),
+            AddressPostalCode::data(),
+            CustomerIdNumber::data(),
+            DocumentDescriminator::data(),
+            CountryIdentification::data(),
+            FederalCommercialVehicleCodes::data(),
+            AddressStreet2::data(),
+            HairColor::data(),
+            PlaceOfBirth::data(),
+            AuditInformation::data(),
+            InventoryControlNumber::data(),
+            AliasFamilyName::data(),
+            AliasGivenName::data(),
+            AliasSuffixName::data(),
+            NameSuffix::data(),
+            PhysicalDescriptionWeightRange::data(),
+            RaceEthnicity::data(),
+            StandardVehicleClassification::data(),
+            StandardEndorsementCode::data(),
+            StandardRestrictionCode::data(),
+            JurisdictionSpecificVehicleClassificationDescription::data(),
+            JurisdictionSpecificEndorsementCodeDescription::data(),
+            JurisdictionSpecificRestrictionCodeDescription::data(),
+            WeightPounds::data(),
+            WeightKilograms::data()
+        ]
+    };
+
+    // Versions 04 through 06, which split the name into DCS, DAC and DAD.
+    static ref VERSION_04_ELEMENTS: Vec<ElementValidationData> = version_04_elements();
+
+    // Version 07 onwards, which added the organ donor and veteran indicators.
     static ref ELEMENTS: Vec<ElementValidationData> = {
-        let mut elements = Vec::new();
-        elements.push(JurisdictionSpecificVehicleClass::data());
-        elements.push(JurisdictionSpecificRestrictionCodes::data());
-        elements.push(JurisdictionSpecificEndorsementCodes::data());
-        elements.push(DocumentExpirationDate::data());
-        elements.push(CustomerFamilyName::data());
-        elements.push(CustomerFirstName::data());
-        elements.push(CustomerMiddleNames::data());
-        elements.push(DocumentIssueDate::data());
-        elements.push(DateOfBirth::data());
-        elements.push(PhysicalDescriptionSex::data());
-        elements.push(PhysicalDescriptionEyeColor::data());
-        elements.push(PhysicalDescriptionHeight::data());
-        elements.push(AddressStreet1::data());
-        elements.push(AddressCity::data());
-        elements.push(AddressJurisdictionCode::data());
-        elements.push(AddressPostalCode::data());
-        elements.push(CustomerIdNumber::data());
-        elements.push(DocumentDescriminator::data());
-        elements.push(CountryIdentification::data());
-        elements.push(FamilyNameTruncation::data());
-        elements.push(FirstNameTruncation::data());
-        elements.push(MiddleNameTruncation::data());
-        elements.push(AddressStreet2::data());
-        elements.push(HairColor::data());
-        elements.push(PlaceOfBirth::data());
-        elements.push(AuditInformation::data());
-        elements.push(InventoryControlNumber::data());
-        elements.push(AliasFamilyName::data());
-        elements.push(AliasGivenName::data());
-        elements.push(AliasSuffixName::data());
-        elements.push(NameSuffix::data());
-        elements.push(PhysicalDescriptionWeightRange::data());
-        elements.push(RaceEthnicity::data());
-        elements.push(StandardVehicleClassification::data());
-        elements.push(StandardEndorsementCode::data());
-        elements.push(StandardRestrictionCode::data());
-        elements.push(JurisdictionSpecificVehicleClassificationDescription::data());
-        elements.push(JurisdictionSpecificEndorsementCodeDescription::data());
-        elements.push(JurisdictionSpecificRestrictionCodeDescription::data());
-        elements.push(DHSComplianceType::data());
-        elements.push(DHSCardRevisionDate::data());
-        elements.push(HAZMATEndorcementExpirationDate::data());
-        elements.push(LimitedDurationDocumentIndicator::data());
-        elements.push(WeightPounds::data());
-        elements.push(WeightKilograms::data());
-        elements.push(Under18Until::data());
-        elements.push(Under19Until::data());
-        elements.push(Under21Until::data());
-        elements.push(OrganDonorIndicator::data());
-        elements.push(VeteranIndicator::data());
+        let mut elements = version_04_elements();
+        elements.extend([OrganDonorIndicator::data(), VeteranIndicator::data()]);
         elements
     };
 );
 
-
+fn version_04_elements() -> Vec<ElementValidationData> {
+    vec![
+        JurisdictionSpecificVehicleClass::data(),
+        JurisdictionSpecificRestrictionCodes::data(),
+        JurisdictionSpecificEndorsementCodes::data(),
+        DocumentExpirationDate::data(),
+        CustomerFamilyName::data(),
+        CustomerFirstName::data(),
+        CustomerMiddleNames::data(),
+        DocumentIssueDate::data(),
+        DateOfBirth::data(),
+        PhysicalDescriptionSex::data(),
+        PhysicalDescriptionEyeColor::data(),
+        PhysicalDescriptionHeight::data(),
+        AddressStreet1::data(),
+        AddressCity::data(),
+        AddressJurisdictionCode::data(),
+        AddressPostalCode::data(),
+        CustomerIdNumber::data(),
+        DocumentDescriminator::data(),
+        CountryIdentification::data(),
+        FamilyNameTruncation::data(),
+        FirstNameTruncation::data(),
+        MiddleNameTruncation::data(),
+        AddressStreet2::data(),
+        HairColor::data(),
+        PlaceOfBirth::data(),
+        AuditInformation::data(),
+        InventoryControlNumber::data(),
+        AliasFamilyName::data(),
+        AliasGivenName::data(),
+        AliasSuffixName::data(),
+        NameSuffix::data(),
+        PhysicalDescriptionWeightRange::data(),
+        RaceEthnicity::data(),
+        StandardVehicleClassification::data(),
+        StandardEndorsementCode::data(),
+        StandardRestrictionCode::data(),
+        JurisdictionSpecificVehicleClassificationDescription::data(),
+        JurisdictionSpecificEndorsementCodeDescription::data(),
+        JurisdictionSpecificRestrictionCodeDescription::data(),
+        DHSComplianceType::data(),
+        DHSCardRevisionDate::data(),
+        HAZMATEndorcementExpirationDate::data(),
+        LimitedDurationDocumentIndicator::data(),
+        WeightPounds::data(),
+        WeightKilograms::data(),
+        Under18Until::data(),
+        Under19Until::data(),
+        Under21Until::data()
+    ]
+}
 
 pub fn by_id(id: String) -> Result<&'static ElementValidationData, Error> {
 
@@ -548,15 +942,239 @@ pub fn by_id(id: String) -> Result<&'static ElementValidationData, Error> {
     Err(Error::ElementNotFound { element_name: id.to_string() })
 }
 
-/*
-// Need to add tests
+/// Checks every element of a subfile against the dictionary of its AAMVA version, returning each violation of the
+/// declared character classes, lengths and document types. Elements missing from the dictionary are not checked.
+pub fn validate_lines(lines: &[&str], aamva_version: u8, document_type: &str) -> Vec<Error> {
+    let mut violations = Vec::new();
+
+    for line in lines {
+        let (id, value) = split_element_line(line);
+
+        let data = match by_id_for_version(id, aamva_version) {
+            Ok(data) => data,
+            Err(_) => continue
+        };
+
+        if !data.applies_to(document_type) {
+            violations.push(Error::DocumentTypeInvalid {
+                element_id: id.to_string(),
+                document_type: document_type.to_string()
+            });
+        }
+
+        if let Err(violation) = data.check(value) {
+            violations.push(violation);
+        }
+    }
+
+    violations.extend(validate_truncation(lines, aamva_version));
+
+    violations
+}
+
+/// A name marked truncated has been cut at the declared length of its element, so it cannot be any shorter.
+fn validate_truncation(lines: &[&str], aamva_version: u8) -> Vec<Error> {
+    let find = |id: &str| lines.iter().find_map(|line| line.strip_prefix(id));
+
+    [("DDE", "DCS"), ("DDF", "DAC"), ("DDG", "DAD")]
+        .iter()
+        .filter(|(truncation_id, _)| find(truncation_id).map(str::trim) == Some("T"))
+        .filter_map(|(_, name_id)| {
+            let data = by_id_for_version(name_id, aamva_version).ok()?;
+            let found = find(name_id)?.trim_end_matches(' ').chars().count();
+            let expected = *data.length as usize;
+
+            (found < expected).then(|| Error::TruncatedNameTooShort { element_id: name_id.to_string(), found, expected })
+        })
+        .collect()
+}
+
+/// The elements defined by an AAMVA version, `None` for version 00 and versions newer than `LATEST_VERSION`.
+pub fn dictionary(aamva_version: u8) -> Option<&'static [ElementValidationData]> {
+    match aamva_version {
+        1 => Some(&VERSION_01_ELEMENTS),
+        2 | 3 => Some(&VERSION_02_ELEMENTS),
+        4..=6 => Some(&VERSION_04_ELEMENTS),
+        7..=LATEST_VERSION => Some(&ELEMENTS),
+        _ => None
+    }
+}
+
+/// Looks an element up in the dictionary of an AAMVA version, versions without a dictionary define no elements.
+pub fn by_id_for_version(id: &str, aamva_version: u8) -> Result<&'static ElementValidationData, Error> {
+    dictionary(aamva_version)
+        .and_then(|elements| elements.iter().find(|element| element.id == id))
+        .ok_or_else(|| Error::ElementNotFound { element_name: id.to_string() })
+}
+
+/// An element the dictionary of the card's AAMVA version does not define, kept as it was encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownElement {
+    pub id: String,
+    pub value: String,
+    /// Index of the line in its subfile.
+    pub position: usize,
+    /// Byte range of the line in the decoded file, `Parsed::decoded`, when the element was read from one.
+    pub span: Option<Range<usize>>
+}
+
+/// One occurrence of a repeated element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    pub value: String,
+    /// Index of the line in its subfile.
+    pub position: usize,
+    /// Byte range of the line in the decoded file, `Parsed::decoded`, when the element was read from one.
+    pub span: Option<Range<usize>>
+}
+
+/// An element ID that appears more than once in a subfile, with every occurrence in the order they appear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateElement {
+    pub id: String,
+    pub occurrences: Vec<Occurrence>
+}
+
+impl DuplicateElement {
+
+    /// Whether the occurrences hold different values, padding aside.
+    pub fn is_conflicting(&self) -> bool {
+        let mut values = self.occurrences.iter().map(|occurrence| occurrence.value.trim_end_matches(' '));
+        let first = values.next();
+
+        values.any(|value| Some(value) != first)
+    }
+}
+
+/// Splits a line into its element ID, the first three characters, and its value. The ID is never longer than three
+/// characters, so a line starting with a character outside ASCII does not become an ID holding the whole value.
+pub(crate) fn split_element_line(line: &str) -> (&str, &str) {
+    let end = line.char_indices().nth(3).map_or(line.len(), |(index, _)| index);
+
+    line.split_at(end)
+}
+
+/// The element IDs that appear more than once in a subfile, in the order of their first occurrence.
+pub fn duplicate_elements<S: AsRef<str>>(lines: &[S]) -> Vec<DuplicateElement> {
+    let mut elements: Vec<DuplicateElement> = Vec::new();
+
+    for (position, line) in lines.iter().map(AsRef::as_ref).enumerate() {
+        let (id, value) = split_element_line(line);
+        let occurrence = Occurrence { value: value.to_string(), position, span: None };
+
+        match elements.iter_mut().find(|element| element.id == id) {
+            Some(element) => element.occurrences.push(occurrence),
+            None => elements.push(DuplicateElement { id: id.to_string(), occurrences: vec![occurrence] })
+        }
+    }
+
+    elements.retain(|element| element.occurrences.len() > 1);
+
+    elements
+}
+
+/// The elements of a subfile that are not in the dictionary of its AAMVA version, in the order they appear.
+pub fn unknown_elements<S: AsRef<str>>(lines: &[S], aamva_version: u8) -> Vec<UnknownElement> {
+    lines.iter()
+        .map(AsRef::as_ref)
+        .enumerate()
+        .filter_map(|(position, line)| {
+            let (id, value) = split_element_line(line);
+
+            by_id_for_version(id, aamva_version).is_err().then(|| UnknownElement {
+                id: id.to_string(),
+                value: value.to_string(),
+                position,
+                span: None
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::elements::by_id;
+    use crate::elements::{by_id, by_id_for_version, dictionary, duplicate_elements, split_element_line, unknown_elements, validate_lines, AddressCity, DateOfBirth, Element, PhysicalDescriptionHeight};
+    use crate::error::Error;
+    use crate::versions::LATEST_VERSION;
+
+    #[test]
+    fn test_lines_outside_ascii() {
+        let lines = ["DAéSECRETVALUE", "DAéOTHERVALUE"];
+
+        assert_eq!(split_element_line("DAéSECRETVALUE"), ("DAé", "SECRETVALUE"));
+        assert_eq!(split_element_line("DA"), ("DA", ""));
+        assert_eq!(unknown_elements(&lines, LATEST_VERSION)[0].id, "DAé");
+        assert_eq!(duplicate_elements(&lines)[0].id, "DAé");
+        assert!(matches!(DateOfBirth::validate("DAéSECRETVALUE"), Err(Error::MatchError { var_1, .. }) if var_1 == "DAé"));
+    }
 
     #[test]
     fn test_by_id() {
-        println!("{:#?}", by_id("DCA".to_string()).unwrap());
+        assert_eq!(by_id("DCA".to_string()).unwrap().document_type, "DL");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(DateOfBirth::validate("DBB08311977").unwrap(), "08311977");
+        assert_eq!(PhysicalDescriptionHeight::validate("DAU069 IN").unwrap(), "069 IN");
+        assert!(matches!(DateOfBirth::validate("DBB0831197A"), Err(Error::InvalidCharacter { character: 'A', position: 7, .. })));
+        assert!(matches!(DateOfBirth::validate("DBB0831197"), Err(Error::InvalidStringLength { found: 7, .. })));
+        assert!(matches!(AddressCity::find_in_lines(&[format!("DAI{}", "X".repeat(60))]), Err(Error::InvalidStringLength { .. })));
+    }
+
+    #[test]
+    fn test_unpadded_values() {
+        for line in ["DCMC", "DCNNONE", "DCONONE", "DAX68", "DAW150", "DAK95818-1234", "DAK95818", "DBCM", "DBC1", "DAU5'-09\""] {
+            let (id, value) = line.split_at(3);
+
+            assert!(by_id(id.to_string()).unwrap().check(value).is_ok(), "{line}");
+        }
+
+        assert!(matches!(by_id("DCM".to_string()).unwrap().check("ABCDE"), Err(Error::InvalidStringLength { found: 5, .. })));
+        assert!(matches!(by_id("DBC".to_string()).unwrap().check("MF"), Err(Error::InvalidStringLength { found: 2, .. })));
+    }
+
+    #[test]
+    fn test_owned_lines() {
+        // Callers written against the `&String` and `&Vec<String>` signatures keep compiling.
+        let line = String::from("DBB08311977");
+        let lines: Vec<String> = vec![String::from("DAQD1234562"), line.clone()];
+
+        assert_eq!(DateOfBirth::validate(&line).unwrap(), "08311977");
+        assert_eq!(DateOfBirth::no_error_validate(&line).as_deref(), Some("08311977"));
+        assert_eq!(DateOfBirth::find_in_lines(&lines).unwrap(), "08311977");
+        assert_eq!(DateOfBirth::no_error_find_in_lines(&lines).as_deref(), Some("08311977"));
+    }
+
+    #[test]
+    fn test_dictionaries() {
+        assert!(by_id_for_version("DAA", 1).is_ok());
+        assert!(by_id_for_version("DCT", 3).is_ok());
+        assert!(by_id_for_version("DCT", 4).is_err());
+        assert!(by_id_for_version("DDH", 4).is_ok());
+        assert!(by_id_for_version("DDL", 6).is_err());
+        assert!(by_id_for_version("DDL", 7).is_ok());
+        assert!(dictionary(0).is_none());
+        assert!(dictionary(LATEST_VERSION + 1).is_none());
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let violations = validate_lines(&["DAQ123", "DCAC", "DBB0831197A", "ZZZUNKNOWN"], 9, "ID");
+
+        assert_eq!(violations.len(), 2);
+        assert!(matches!(&violations[0], Error::DocumentTypeInvalid { element_id, .. } if element_id == "DCA"));
+        assert!(matches!(&violations[1], Error::InvalidCharacter { element_id, .. } if element_id == "DBB"));
+    }
+
+    #[test]
+    fn test_duplicate_elements() {
+        let duplicates = duplicate_elements(&["DAQ123", "DBB08311977", "DAQ123 ", "DBB08311978", "DBB08311977", "DCSSAMPLE"]);
+
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[0].id, "DAQ");
+        assert!(!duplicates[0].is_conflicting());
+        assert_eq!(duplicates[1].occurrences.iter().map(|occurrence| occurrence.position).collect::<Vec<_>>(), vec![1, 3, 4]);
+        assert!(duplicates[1].is_conflicting());
     }
 }
-*/
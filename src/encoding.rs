@@ -0,0 +1,103 @@
+/// Records how the raw payload was turned into text before parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoding {
+    /// Every byte was ASCII, so no choice of encoding had to be made.
+    Ascii,
+    /// The payload was valid UTF-8 and was used as is.
+    Utf8,
+    /// The payload was decoded as ISO-8859-1 (Latin-1), the encoding required by the AAMVA standard.
+    Latin1,
+    /// The payload contained bytes in the 0x80 to 0x9F range, which are control characters in Latin-1, so it was
+    /// decoded as Windows-1252 instead.
+    Windows1252,
+    /// The payload had previously been decoded with the wrong encoding (UTF-8 text read as Latin-1 or
+    /// Windows-1252, such as "Ã©" for "é") and was repaired.
+    RepairedMojibake,
+    /// The payload contains U+FFFD replacement characters, meaning Latin-1 bytes were once decoded as UTF-8 and the
+    /// original characters were lost.
+    Lossy
+}
+
+// Windows-1252 characters for the bytes 0x80 to 0x9F, bytes left undefined by Windows-1252 keep their Latin-1 meaning.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}'
+];
+
+/// Decodes a raw barcode payload. Payloads that are valid UTF-8 are taken to have been re-encoded by the scanner, since
+/// Latin-1 text rarely forms valid UTF-8 by chance, everything else is decoded as Latin-1 (or Windows-1252). Latin-1
+/// text that does, such as "Ã©" (C3 A9), is therefore read as UTF-8 ("é").
+pub fn decode(bytes: &[u8]) -> (String, Decoding) {
+    if bytes.is_ascii() {
+        return (bytes.iter().map(|&byte| byte as char).collect(), Decoding::Ascii);
+    }
+
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return repair(text.to_string());
+    }
+
+    if bytes.iter().any(|byte| (0x80..=0x9F).contains(byte)) {
+        return (bytes.iter().map(|&byte| windows_1252_char(byte)).collect(), Decoding::Windows1252);
+    }
+
+    (bytes.iter().map(|&byte| byte as char).collect(), Decoding::Latin1)
+}
+
+/// Detects text that was decoded with the wrong encoding and repairs it where the original characters survived.
+pub fn repair(text: String) -> (String, Decoding) {
+    if text.is_ascii() {
+        return (text, Decoding::Ascii);
+    }
+
+    if text.contains('\u{FFFD}') {
+        return (text, Decoding::Lossy);
+    }
+
+    let bytes: Option<Vec<u8>> = text.chars().map(single_byte).collect();
+
+    match bytes.map(String::from_utf8) {
+        Some(Ok(repaired)) => (repaired, Decoding::RepairedMojibake),
+        _ => (text, Decoding::Utf8)
+    }
+}
+
+fn windows_1252_char(byte: u8) -> char {
+    match byte {
+        0x80..=0x9F => WINDOWS_1252[(byte - 0x80) as usize],
+        _ => byte as char
+    }
+}
+
+// The byte a character would have had in Windows-1252 or Latin-1, if any.
+fn single_byte(character: char) -> Option<u8> {
+    if let Some(index) = WINDOWS_1252.iter().position(|&c| c == character) {
+        return Some(0x80 + index as u8);
+    }
+
+    u8::try_from(u32::from(character)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::{decode, repair, Decoding};
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(b"DCSTREMBLAY"), ("DCSTREMBLAY".to_string(), Decoding::Ascii));
+        assert_eq!(decode(b"DACH\xc9L\xc8NE"), ("DACHÉLÈNE".to_string(), Decoding::Latin1));
+        assert_eq!(decode(b"DCSO\x92BRIEN"), ("DCSO\u{2019}BRIEN".to_string(), Decoding::Windows1252));
+        assert_eq!(decode("DACHÉLÈNE".as_bytes()), ("DACHÉLÈNE".to_string(), Decoding::Utf8));
+
+        // Latin-1 for "Ã©" is also UTF-8 for "é", which wins.
+        assert_eq!(decode(b"DACJOS\xc3\xa9"), ("DACJOSé".to_string(), Decoding::Utf8));
+    }
+
+    #[test]
+    fn test_repair() {
+        assert_eq!(repair("DACHÃ‰LÃˆNE".to_string()), ("DACHÉLÈNE".to_string(), Decoding::RepairedMojibake));
+        assert_eq!(repair("DACNUÃ±EZ".to_string()), ("DACNUñEZ".to_string(), Decoding::RepairedMojibake));
+        assert_eq!(repair("DACH\u{FFFD}L\u{FFFD}NE".to_string()).1, Decoding::Lossy);
+    }
+}
@@ -8,7 +8,7 @@ pub enum Error {
     #[snafu(display("Formatting error, violation:\n{violation}"))]
     Formmating { violation: String },
 
-    #[snafu(display("File type should be 'ANSI ', please check your headers."))]
+    #[snafu(display("File type should be 'ANSI ' or 'AAMVA', please check your headers."))]
     InvalidFileType,
 
     #[snafu(display("Error with threading lock in static element structs"))]
@@ -17,12 +17,72 @@ pub enum Error {
     #[snafu(display("Element with name '{element_name}' not found"))]
     ElementNotFound { element_name: String },
 
-    #[snafu(display("Element has invalid document_type: {document_type}, document_type must be one of 'Both', 'ID', 'DL"))]
-    DocumentTypeInvalid { document_type: String },
+    #[snafu(display("Element '{element_id}' is not defined by AAMVA version {aamva_version}"))]
+    UnknownElement { element_id: String, aamva_version: u8 },
+
+    #[snafu(display("AAMVA version {aamva_version} has no element dictionary, the card is read as version {read_as}"))]
+    UnknownVersion { aamva_version: u8, read_as: u8 },
+
+    #[snafu(display("Element '{element_id}' is not allowed on a '{document_type}' document"))]
+    DocumentTypeInvalid { element_id: String, document_type: String },
 
     #[snafu(display("Match error: {var_1} does not match {var_2}"))]
     MatchError { var_1: String, var_2: String },
 
-    #[snafu(display("Invalid length of string"))]
-    InvalidStringLength
+    #[snafu(display("Invalid length of string, element '{element_id}' is {found} characters long, expected {expected}"))]
+    InvalidStringLength { element_id: String, found: usize, expected: String },
+
+    #[snafu(display("Element '{element_id}' does not allow the character '{character}' at position {position}"))]
+    InvalidCharacter { element_id: String, character: char, position: usize },
+
+    #[snafu(display("Header for AAMVA version {aamva_version} requires {expected} characters, found {found}"))]
+    HeaderTooShort { aamva_version: u8, expected: usize, found: usize },
+
+    #[snafu(display("Header field '{field}' must be numeric, found '{value}'"))]
+    InvalidNumber { field: String, value: String },
+
+    #[snafu(display("Header field '{field}' is {value}, expected {expected}"))]
+    OutOfRange { field: String, value: usize, expected: String },
+
+    #[snafu(display("Subfile type '{subfile_type}' should be 'DL', 'ID', 'EN' or a jurisdiction specific 'Z' code"))]
+    InvalidSubfileType { subfile_type: String },
+
+    #[snafu(display("Subfile '{subfile_type}' at offset {offset} with length {length} does not fit in a file of {file_length} bytes"))]
+    SubfileOutOfBounds { subfile_type: String, offset: usize, length: usize, file_length: usize },
+
+    #[snafu(display("Subfile at offset {offset} should start with '{expected}', found '{found}'"))]
+    SubfileTypeMismatch { offset: usize, expected: String, found: String },
+
+    #[snafu(display("No subfile of type {expected} found in the header"))]
+    SubfileNotFound { expected: String },
+
+    #[snafu(display("Element '{element_id}' holds the invalid date '{value}', {reason}"))]
+    InvalidDate { element_id: String, value: String, reason: String },
+
+    #[snafu(display("Element '{element_id}' holds '{value}', which marks the date as unknown"))]
+    UnknownDate { element_id: String, value: String },
+
+    #[snafu(display("Invalid height '{value}', {reason}"))]
+    InvalidHeight { value: String, reason: String },
+
+    #[snafu(display("Element '{element_id}' holds '{value}', which is not in the AAMVA code table"))]
+    UnknownCode { element_id: String, value: String },
+
+    #[snafu(display("Weight {weight} does not fall in the weight range {range} of DCE"))]
+    WeightMismatch { weight: String, range: u8 },
+
+    #[snafu(display("Element '{element_id}' is marked truncated but is {found} characters long, a truncated name fills all {expected}"))]
+    TruncatedNameTooShort { element_id: String, found: usize, expected: usize },
+
+    #[snafu(display("Element '{element_id}' holds {found}, but the date of birth implies {expected}"))]
+    AgeThresholdMismatch { element_id: String, found: chrono::NaiveDate, expected: chrono::NaiveDate },
+
+    #[snafu(display("Element '{element_id}' appears {occurrences} times{}", if *conflicting { " with different values" } else { "" }))]
+    DuplicateElement { element_id: String, occurrences: usize, conflicting: bool },
+
+    #[snafu(display("The file does not start with the '@', LF, RS and CR separators, the header starts at byte {position}"))]
+    UnexpectedSeparators { position: usize },
+
+    #[snafu(display("Rejected by the parser options, {diagnostic}"))]
+    Rejected { diagnostic: crate::diagnostics::Diagnostic }
 }
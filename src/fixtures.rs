@@ -0,0 +1,46 @@
+/// Header prefix for a version 09 California card, everything between the segment terminator and the number of entries.
+pub(crate) const CALIFORNIA_V09: &str = "ANSI 6360140900";
+
+pub(crate) const DRIVERS_LICENSE: &[&str] = &[
+    "DAQD1234562",
+    "DCSSAMPLE",
+    "DACJOHN",
+    "DADQUINCY",
+    "DBA08312030",
+    "DBB08311977",
+    "DBD08312020",
+    "DBC1",
+    "DAYBRO",
+    "DAU069 IN",
+    "DAG2570 24TH STREET",
+    "DAISACRAMENTO",
+    "DAJCA",
+    "DAK958180000  ",
+    "DCFDOCDISCRIM",
+    "DCGUSA",
+    "DDEN",
+    "DDFN",
+    "DDGN",
+    "DCAC",
+    "DCBNONE",
+    "DCDNONE"
+];
+
+/// Assembles a file with a correct subfile directory from `(subfile_type, element lines)` pairs, counting offsets
+/// in Latin-1 bytes.
+pub(crate) fn build_file(header: &str, subfiles: &[(&str, &[&str])]) -> String {
+    let bodies: Vec<String> = subfiles.iter()
+        .map(|(subfile_type, lines)| format!("{subfile_type}{}\r", lines.join("\n")))
+        .collect();
+
+    let mut offset = 4 + header.chars().count() + 2 + subfiles.len() * 10;
+    let mut directory = format!("{:02}", subfiles.len());
+
+    for ((subfile_type, _), body) in subfiles.iter().zip(&bodies) {
+        let length = body.chars().count();
+        directory.push_str(&format!("{subfile_type}{offset:04}{length:04}"));
+        offset += length;
+    }
+
+    format!("@\n\x1e\r{header}{directory}{}", bodies.concat())
+}
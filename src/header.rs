@@ -1,64 +1,438 @@
 use std::fmt;
+use std::str::FromStr;
+
 use crate::error::Error;
+use crate::jurisdiction::Jurisdiction;
+use crate::versions::LATEST_VERSION;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubfileType {
+    /// DL, a drivers license.
+    DriversLicense,
+    /// ID, a state identification card.
+    IdentificationCard,
+    /// EN, an enhanced drivers license.
+    EnhancedDriversLicense,
+    /// A jurisdiction specific subfile, Z followed by the given letter such as ZV or ZC.
+    Jurisdiction(char)
+}
+
+impl SubfileType {
+
+    pub fn is_jurisdiction_specific(&self) -> bool {
+        matches!(self, SubfileType::Jurisdiction(_))
+    }
+
+    /// The document type elements are declared against, enhanced licenses are drivers licenses.
+    pub fn document_type(&self) -> Option<&'static str> {
+        match self {
+            SubfileType::DriversLicense | SubfileType::EnhancedDriversLicense => Some("DL"),
+            SubfileType::IdentificationCard => Some("ID"),
+            SubfileType::Jurisdiction(_) => None
+        }
+    }
+}
+
+impl FromStr for SubfileType {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<SubfileType, Error> {
+        match code.as_bytes() {
+            b"DL" => Ok(SubfileType::DriversLicense),
+            b"ID" => Ok(SubfileType::IdentificationCard),
+            b"EN" => Ok(SubfileType::EnhancedDriversLicense),
+            [b'Z', letter] if letter.is_ascii_uppercase() => Ok(SubfileType::Jurisdiction(*letter as char)),
+            _ => Err(Error::InvalidSubfileType { subfile_type: code.to_string() })
+        }
+    }
+}
+
+impl fmt::Display for SubfileType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubfileType::DriversLicense => write!(f, "DL"),
+            SubfileType::IdentificationCard => write!(f, "ID"),
+            SubfileType::EnhancedDriversLicense => write!(f, "EN"),
+            SubfileType::Jurisdiction(letter) => write!(f, "Z{letter}")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubfileDesignator {
+    subfile_type: SubfileType,
+    offset: u16,
+    length: u16
+}
+
+impl SubfileDesignator {
+
+    pub(crate) fn new(subfile_type: SubfileType, offset: u16, length: u16) -> SubfileDesignator {
+        SubfileDesignator { subfile_type, offset, length }
+    }
+
+    pub fn subfile_type(&self) -> SubfileType {
+        self.subfile_type
+    }
+
+    /// Offset of the subfile from the start of the file, pointing at its subfile type code.
+    pub fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// Length of the subfile, including its subfile type code and segment terminator.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+}
 
 #[derive(Debug)]
 pub struct Header {
     file_type: String,
-    issuer_identification_number: String,
-    aamva_version_number: String,
-    jurisdiction_version_number: String,
-    number_of_entries: String,
-    // A subfile type of DL means that its a drivers license, ID means state ID, and EN means enhanced drivers license
-    subfile_type: String,
-    offset: String,
-    length: String,
-    extra_characters: String
+    issuer_identification_number: u32,
+    aamva_version_number: u8,
+    jurisdiction_version_number: Option<u8>,
+    number_of_entries: u8,
+    subfile_designators: Vec<SubfileDesignator>
 }
 
 impl Header {
-    
+
     pub fn from(header: String) -> Result<Header, Error>{
+        HeaderRef::parse(&header).map(|header| header.to_owned())
+    }
+
+    /// "ANSI " on every current card, "AAMVA" on some version 01 cards.
+    pub fn file_type(&self) -> &str {
+        &self.file_type
+    }
+
+    pub fn issuer_identification_number(&self) -> u32 {
+        self.issuer_identification_number
+    }
+
+    /// The issuing jurisdiction, None when the issuer identification number is not a known one.
+    pub fn jurisdiction(&self) -> Option<Jurisdiction> {
+        Jurisdiction::from_iin(self.issuer_identification_number)
+    }
+
+    pub fn aamva_version_number(&self) -> u8 {
+        self.aamva_version_number
+    }
+
+    /// Missing on version 01 cards, whose header has no jurisdiction version field.
+    pub fn jurisdiction_version_number(&self) -> Option<u8> {
+        self.jurisdiction_version_number
+    }
+
+    pub fn number_of_entries(&self) -> u8 {
+        self.number_of_entries
+    }
+
+    pub fn subfile_designators(&self) -> &[SubfileDesignator] {
+        &self.subfile_designators
+    }
+}
+
+/// Borrowed form of `Header`, the file type is a slice of the input it was parsed from.
+#[derive(Debug)]
+pub struct HeaderRef<'a> {
+    file_type: &'a str,
+    issuer_identification_number: u32,
+    aamva_version_number: u8,
+    jurisdiction_version_number: Option<u8>,
+    number_of_entries: u8,
+    subfile_designators: Vec<SubfileDesignator>
+}
+
+impl<'a> HeaderRef<'a> {
+
+    /// Parses the header starting at its record separator, anything after the subfile designators is ignored.
+    pub fn parse(header: &'a str) -> Result<HeaderRef<'a>, Error> {
         let mut characters = header;
 
-        if let Some('\x1e') = characters.chars().next() {
-            characters.remove(0);
-        } else {
-            return Err(Error::Formmating { violation: "Record separator not found".to_string() });
+        characters = match characters.strip_prefix('\x1e') {
+            Some(characters) => characters,
+            None => return Err(Error::Formmating { violation: "Record separator not found".to_string() })
         };
 
-        if let Some('\x0d') = characters.chars().next() {
-            characters.remove(0);
-        } else {
-            return Err(Error::Formmating { violation: "Segment terminator not found".to_string() });
+        characters = match characters.strip_prefix('\x0d') {
+            Some(characters) => characters,
+            None => return Err(Error::Formmating { violation: "Segment terminator not found".to_string() })
         };
 
-        let file_type: String = characters.drain(00..05).collect();
+        HeaderRef::parse_fields(characters)
+    }
+
+    /// Parses the header fields that follow the segment terminator, starting at the file type.
+    pub fn parse_fields(fields: &'a str) -> Result<HeaderRef<'a>, Error> {
+        let mut characters = fields;
+        let layout = HeaderLayout::for_version(version_digits(fields)?);
+        layout.ensure_length(fields, 0)?;
+
+        let file_type = take(&mut characters, 5)?;
 
-        if file_type != "ANSI ".to_string() {
+        if !FILE_TYPES.contains(&file_type) {
             return Err(Error::InvalidFileType);
         }
 
-        let issuer_identification_number: String = characters.drain(00..06).collect();
-
-        // Todo: Ensure the below three values are between 0 and 99 or return err result
-        let aamva_version_number: String = characters.drain(00..02).collect();
-        let jurisdiction_version_number: String = characters.drain(00..02).collect();
-        let number_of_entries: String = characters.drain(00..02).collect();
-        let subfile_type: String = characters.drain(00..02).collect();
-        let offset: String = characters.drain(00..04).collect();
-        let length: String = characters.drain(00..04).collect();
-
-
-        Ok(Header { 
-            extra_characters: characters,
-            file_type, 
-            issuer_identification_number, 
-            aamva_version_number, 
-            jurisdiction_version_number, 
-            number_of_entries, 
-            subfile_type, 
-            offset, 
-            length
+        let issuer_identification_number = parse_number("issuer_identification_number", take(&mut characters, 6)?)?;
+        let aamva_version_number = parse_number("aamva_version_number", take(&mut characters, 2)?)?;
+        let jurisdiction_version_number = match layout.jurisdiction_version {
+            true => Some(parse_number("jurisdiction_version_number", take(&mut characters, 2)?)?),
+            false => None
+        };
+        let number_of_entries: u8 = parse_number("number_of_entries", take(&mut characters, 2)?)?;
+
+        if number_of_entries == 0 {
+            return Err(Error::OutOfRange {
+                field: "number_of_entries".to_string(),
+                value: 0,
+                expected: "at least one subfile".to_string()
+            });
+        }
+
+        layout.ensure_length(fields, number_of_entries as usize)?;
+
+        // Offsets count from the "@" compliance indicator and its data element separator, two characters before the
+        // record separator.
+        let header_end = 4 + layout.length(number_of_entries as usize);
+        let mut subfile_designators = Vec::new();
+
+        for _ in 0..number_of_entries {
+            let subfile_type = take(&mut characters, 2)?.parse()?;
+            let offset: u16 = parse_number("offset", take(&mut characters, 4)?)?;
+            let length: u16 = parse_number("length", take(&mut characters, 4)?)?;
+
+            if (offset as usize) < header_end {
+                return Err(Error::OutOfRange {
+                    field: "offset".to_string(),
+                    value: offset as usize,
+                    expected: format!("at least {header_end}, the end of the header")
+                });
+            }
+
+            // The shortest subfile is its type code followed by the segment terminator.
+            if length < 3 {
+                return Err(Error::OutOfRange {
+                    field: "length".to_string(),
+                    value: length as usize,
+                    expected: "at least 3".to_string()
+                });
+            }
+
+            subfile_designators.push(SubfileDesignator { subfile_type, offset, length });
+        }
+
+        Ok(HeaderRef {
+            file_type,
+            issuer_identification_number,
+            aamva_version_number,
+            jurisdiction_version_number,
+            number_of_entries,
+            subfile_designators
         })
-    }    
+    }
+
+    /// Reads what it can of a header that does not parse, fields that cannot be read are zero or, for the AAMVA
+    /// version, the latest version. The subfile directory is left empty.
+    pub fn recover(fields: &'a str) -> HeaderRef<'a> {
+        let number = |range: std::ops::Range<usize>| fields.get(range).and_then(|value| parse_number::<u32>("", value).ok());
+
+        let aamva_version_number = number(11..VERSION_END).and_then(|version| u8::try_from(version).ok()).unwrap_or(LATEST_VERSION);
+        let jurisdiction_version_number = match aamva_version_number {
+            1 => None,
+            _ => number(VERSION_END..VERSION_END + 2).and_then(|version| u8::try_from(version).ok())
+        };
+
+        HeaderRef {
+            file_type: fields.get(..5).unwrap_or_default(),
+            issuer_identification_number: number(5..11).unwrap_or(0),
+            aamva_version_number,
+            jurisdiction_version_number,
+            number_of_entries: 0,
+            subfile_designators: Vec::new()
+        }
+    }
+
+    pub fn file_type(&self) -> &'a str {
+        self.file_type
+    }
+
+    pub fn issuer_identification_number(&self) -> u32 {
+        self.issuer_identification_number
+    }
+
+    /// The issuing jurisdiction, None when the issuer identification number is not a known one.
+    pub fn jurisdiction(&self) -> Option<Jurisdiction> {
+        Jurisdiction::from_iin(self.issuer_identification_number)
+    }
+
+    pub fn aamva_version_number(&self) -> u8 {
+        self.aamva_version_number
+    }
+
+    pub fn jurisdiction_version_number(&self) -> Option<u8> {
+        self.jurisdiction_version_number
+    }
+
+    pub fn number_of_entries(&self) -> u8 {
+        self.number_of_entries
+    }
+
+    pub fn subfile_designators(&self) -> &[SubfileDesignator] {
+        &self.subfile_designators
+    }
+
+    pub fn to_owned(&self) -> Header {
+        Header {
+            file_type: self.file_type.to_string(),
+            issuer_identification_number: self.issuer_identification_number,
+            aamva_version_number: self.aamva_version_number,
+            jurisdiction_version_number: self.jurisdiction_version_number,
+            number_of_entries: self.number_of_entries,
+            subfile_designators: self.subfile_designators.clone()
+        }
+    }
+}
+
+// "ANSI " is used by every version of the standard, some version 01 cards were issued with "AAMVA" instead.
+const FILE_TYPES: [&str; 2] = ["ANSI ", "AAMVA"];
+
+// The fields before the version number, the file type and issuer identification number, plus the version itself.
+const VERSION_END: usize = 13;
+
+const DESIGNATOR_LENGTH: usize = 10;
+
+struct HeaderLayout {
+    aamva_version: u8,
+    // Version 01 headers go straight from the AAMVA version number to the number of entries.
+    jurisdiction_version: bool
+}
+
+impl HeaderLayout {
+
+    fn for_version(aamva_version: u8) -> HeaderLayout {
+        HeaderLayout { aamva_version, jurisdiction_version: aamva_version != 1 }
+    }
+
+    // Length of the header fields after the segment terminator, up to and including the subfile designators.
+    fn length(&self, entries: usize) -> usize {
+        let jurisdiction_version = if self.jurisdiction_version { 2 } else { 0 };
+
+        VERSION_END + jurisdiction_version + 2 + entries * DESIGNATOR_LENGTH
+    }
+
+    fn ensure_length(&self, fields: &str, entries: usize) -> Result<(), Error> {
+        let expected = self.length(entries);
+        let found = fields.len();
+
+        if found < expected {
+            return Err(Error::HeaderTooShort { aamva_version: self.aamva_version, expected, found });
+        }
+
+        Ok(())
+    }
+}
+
+fn version_digits(fields: &str) -> Result<u8, Error> {
+    match fields.get(11..VERSION_END) {
+        Some(digits) => parse_number("aamva_version_number", digits),
+        None => Err(Error::Formmating { violation: "Header is truncated before the AAMVA version number".to_string() })
+    }
+}
+
+fn take<'a>(characters: &mut &'a str, length: usize) -> Result<&'a str, Error> {
+    match characters.split_at_checked(length) {
+        Some((taken, rest)) => {
+            *characters = rest;
+            Ok(taken)
+        },
+        None => Err(Error::Formmating { violation: "Header is truncated".to_string() })
+    }
+}
+
+// Header numbers are fixed width and zero padded, so anything other than ASCII digits is rejected, including the
+// signs and whitespace `str::parse` would otherwise accept.
+fn parse_number<T: FromStr>(field: &str, value: &str) -> Result<T, Error> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(Error::InvalidNumber { field: field.to_string(), value: value.to_string() });
+    }
+
+    value.parse().map_err(|_| Error::InvalidNumber { field: field.to_string(), value: value.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::header::{HeaderRef, SubfileType};
+    use crate::jurisdiction::Jurisdiction;
+    use crate::versions::LATEST_VERSION;
+
+    #[test]
+    fn test_typed_header() {
+        let header = HeaderRef::parse("\x1e\rANSI 636014090102DL00410278ZC03190008").unwrap();
+
+        assert_eq!(header.file_type(), "ANSI ");
+        assert_eq!(header.issuer_identification_number(), 636014);
+        assert_eq!(header.jurisdiction(), Some(Jurisdiction::California));
+        assert_eq!(header.aamva_version_number(), 9);
+        assert_eq!(header.jurisdiction_version_number(), Some(1));
+        assert_eq!(header.number_of_entries(), 2);
+
+        let designator = header.subfile_designators()[1];
+        assert_eq!(designator.subfile_type(), SubfileType::Jurisdiction('C'));
+        assert_eq!(designator.subfile_type().to_string(), "ZC");
+        assert_eq!((designator.offset(), designator.length()), (319, 8));
+    }
+
+    #[test]
+    fn test_invalid_fields() {
+        assert!(matches!(
+            HeaderRef::parse("\x1e\rANSI 63601409010AXX00410278"),
+            Err(Error::InvalidNumber { .. })
+        ));
+        assert!(matches!(
+            HeaderRef::parse("\x1e\rANSI 636014090101XX00410278"),
+            Err(Error::InvalidSubfileType { .. })
+        ));
+        assert!(matches!(
+            HeaderRef::parse("\x1e\rANSI 636014090101DL00200278"),
+            Err(Error::OutOfRange { value: 20, .. })
+        ));
+        assert!(matches!(
+            HeaderRef::parse("\x1e\rANSI 636014090100"),
+            Err(Error::OutOfRange { value: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_recover() {
+        let header = HeaderRef::recover("ANSI 63601409X1");
+
+        assert_eq!(header.issuer_identification_number(), 636014);
+        assert_eq!(header.aamva_version_number(), 9);
+        assert_eq!(header.jurisdiction_version_number(), None);
+        assert!(header.subfile_designators().is_empty());
+        assert_eq!(HeaderRef::recover("AN").aamva_version_number(), LATEST_VERSION);
+    }
+
+    #[test]
+    fn test_version_01_layout() {
+        let header = HeaderRef::parse("\x1e\rAAMVA6360000101DL00290100").unwrap();
+
+        assert_eq!(header.aamva_version_number(), 1);
+        assert_eq!(header.jurisdiction_version_number(), None);
+        assert_eq!(header.subfile_designators().len(), 1);
+        assert_eq!(header.subfile_designators()[0].offset(), 29);
+    }
+
+    #[test]
+    fn test_short_header() {
+        assert!(matches!(
+            HeaderRef::parse("\x1e\rANSI 636014090002DL00410278"),
+            Err(Error::HeaderTooShort { aamva_version: 9, expected: 37, found: 27 })
+        ));
+        assert!(matches!(HeaderRef::parse("\x1e\rANSI 6360"), Err(Error::Formmating { .. })));
+    }
 }
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+const CENTIMETERS_PER_INCH: f64 = 2.54;
+
+// Anything outside two to nine feet is a misread or a typo rather than a person.
+const PLAUSIBLE_INCHES: std::ops::RangeInclusive<u16> = 24..=108;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeightUnit {
+    Inches,
+    Centimeters
+}
+
+/// Height of the cardholder (DAU) in the unit it was encoded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Height {
+    value: u16,
+    unit: HeightUnit
+}
+
+impl Height {
+
+    pub fn new(value: u16, unit: HeightUnit) -> Result<Height, Error> {
+        let height = Height { value, unit };
+
+        if !PLAUSIBLE_INCHES.contains(&height.to_inches()) {
+            return Err(Error::InvalidHeight {
+                value: height.to_string(),
+                reason: "expected a height between 2 and 9 feet".to_string()
+            });
+        }
+
+        Ok(height)
+    }
+
+    pub fn value(&self) -> u16 {
+        self.value
+    }
+
+    pub fn unit(&self) -> HeightUnit {
+        self.unit
+    }
+
+    /// The height in whole inches, rounded when it was encoded in centimetres.
+    pub fn to_inches(&self) -> u16 {
+        match self.unit {
+            HeightUnit::Inches => self.value,
+            HeightUnit::Centimeters => (f64::from(self.value) / CENTIMETERS_PER_INCH).round() as u16
+        }
+    }
+
+    /// The height in whole centimetres, rounded when it was encoded in inches.
+    pub fn to_centimeters(&self) -> u16 {
+        match self.unit {
+            HeightUnit::Inches => (f64::from(self.value) * CENTIMETERS_PER_INCH).round() as u16,
+            HeightUnit::Centimeters => self.value
+        }
+    }
+
+    /// The height as whole feet and the remaining inches.
+    pub fn to_feet_and_inches(&self) -> (u16, u16) {
+        let inches = self.to_inches();
+
+        (inches / 12, inches % 12)
+    }
+}
+
+/// Accepts the "069 IN" and "175 CM" forms of the standard, with or without the space, and the legacy `5'-09"` form
+/// written by older cards. A bare number is taken as inches.
+impl FromStr for Height {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Height, Error> {
+        let invalid = |reason: &str| Error::InvalidHeight { value: value.to_string(), reason: reason.to_string() };
+        let number = |digits: &str| digits.trim().parse::<u16>().map_err(|_| invalid("expected a number"));
+
+        let trimmed = value.trim().to_ascii_uppercase();
+
+        if let Some((feet, inches)) = trimmed.split_once('\'') {
+            let inches = inches.trim_start_matches(['-', ' ']).trim_end_matches('"');
+            let inches = if inches.is_empty() { 0 } else { number(inches)? };
+
+            if inches >= 12 {
+                return Err(invalid("inches must be below 12 when feet are given"));
+            }
+
+            let inches = number(feet)?.checked_mul(12).and_then(|feet| feet.checked_add(inches));
+
+            return Height::new(inches.ok_or_else(|| invalid("expected a height between 2 and 9 feet"))?, HeightUnit::Inches);
+        }
+
+        if let Some(centimeters) = trimmed.strip_suffix("CM") {
+            return Height::new(number(centimeters)?, HeightUnit::Centimeters);
+        }
+
+        Height::new(number(trimmed.strip_suffix("IN").unwrap_or(&trimmed))?, HeightUnit::Inches)
+    }
+}
+
+/// Formats the height the way the standard encodes it, such as "069 IN".
+impl fmt::Display for Height {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            HeightUnit::Inches => write!(f, "{:03} IN", self.value),
+            HeightUnit::Centimeters => write!(f, "{:03} CM", self.value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::height::{Height, HeightUnit};
+
+    #[test]
+    fn test_parse() {
+        let height: Height = "069 IN".parse().unwrap();
+        assert_eq!((height.value(), height.unit()), (69, HeightUnit::Inches));
+        assert_eq!(height.to_centimeters(), 175);
+        assert_eq!(height.to_feet_and_inches(), (5, 9));
+
+        let height: Height = "175CM".parse().unwrap();
+        assert_eq!((height.value(), height.unit()), (175, HeightUnit::Centimeters));
+        assert_eq!(height.to_inches(), 69);
+        assert_eq!(height.to_string(), "175 CM");
+
+        assert_eq!("5'-09\"".parse::<Height>().unwrap(), Height::new(69, HeightUnit::Inches).unwrap());
+        assert_eq!("6'".parse::<Height>().unwrap().to_inches(), 72);
+        assert_eq!("070".parse::<Height>().unwrap().to_string(), "070 IN");
+    }
+
+    #[test]
+    fn test_implausible() {
+        assert!(matches!("000 IN".parse::<Height>(), Err(Error::InvalidHeight { .. })));
+        assert!(matches!("690 IN".parse::<Height>(), Err(Error::InvalidHeight { .. })));
+        assert!(matches!("5'-13\"".parse::<Height>(), Err(Error::InvalidHeight { .. })));
+        assert!(matches!("TALL".parse::<Height>(), Err(Error::InvalidHeight { .. })));
+    }
+}
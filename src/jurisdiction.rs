@@ -0,0 +1,292 @@
+use std::fmt;
+
+use chrono_tz::Tz;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Country {
+    UnitedStates,
+    Canada,
+    Mexico
+}
+
+impl Country {
+
+    /// The three letter code used by the country identification element, DCG.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Country::UnitedStates => "USA",
+            Country::Canada => "CAN",
+            Country::Mexico => "MEX"
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Country> {
+        match code.trim() {
+            "USA" => Some(Country::UnitedStates),
+            "CAN" => Some(Country::Canada),
+            "MEX" => Some(Country::Mexico),
+            _ => None
+        }
+    }
+}
+
+/// A jurisdiction that issues AAMVA compliant cards, identified in the header by its issuer identification number
+/// and in the DAJ element by its postal abbreviation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Jurisdiction {
+    // States and the District of Columbia
+    Alabama,
+    Alaska,
+    Arizona,
+    Arkansas,
+    California,
+    Colorado,
+    Connecticut,
+    Delaware,
+    DistrictOfColumbia,
+    Florida,
+    Georgia,
+    Hawaii,
+    Idaho,
+    Illinois,
+    Indiana,
+    Iowa,
+    Kansas,
+    Kentucky,
+    Louisiana,
+    Maine,
+    Maryland,
+    Massachusetts,
+    Michigan,
+    Minnesota,
+    Mississippi,
+    Missouri,
+    Montana,
+    Nebraska,
+    Nevada,
+    NewHampshire,
+    NewJersey,
+    NewMexico,
+    NewYork,
+    NorthCarolina,
+    NorthDakota,
+    Ohio,
+    Oklahoma,
+    Oregon,
+    Pennsylvania,
+    RhodeIsland,
+    SouthCarolina,
+    SouthDakota,
+    Tennessee,
+    Texas,
+    Utah,
+    Vermont,
+    Virginia,
+    Washington,
+    WestVirginia,
+    Wisconsin,
+    Wyoming,
+
+    // US territories
+    AmericanSamoa,
+    Guam,
+    NorthernMarianaIslands,
+    PuertoRico,
+    UsVirginIslands,
+
+    // Canadian provinces and territories
+    Alberta,
+    BritishColumbia,
+    Manitoba,
+    NewBrunswick,
+    NewfoundlandAndLabrador,
+    NorthwestTerritories,
+    NovaScotia,
+    Nunavut,
+    Ontario,
+    PrinceEdwardIsland,
+    Quebec,
+    Saskatchewan,
+    Yukon,
+
+    // Mexican states
+    Coahuila,
+    Hidalgo
+}
+
+struct JurisdictionData {
+    jurisdiction: Jurisdiction,
+    iin: u32,
+    abbreviation: &'static str,
+    country: Country,
+    name: &'static str,
+    time_zone: &'static str
+}
+
+const JURISDICTIONS: [JurisdictionData; 71] = [
+    JurisdictionData { jurisdiction: Jurisdiction::Alabama, iin: 636033, abbreviation: "AL", country: Country::UnitedStates, name: "Alabama", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Alaska, iin: 636059, abbreviation: "AK", country: Country::UnitedStates, name: "Alaska", time_zone: "America/Anchorage" },
+    JurisdictionData { jurisdiction: Jurisdiction::Arizona, iin: 636026, abbreviation: "AZ", country: Country::UnitedStates, name: "Arizona", time_zone: "America/Phoenix" },
+    JurisdictionData { jurisdiction: Jurisdiction::Arkansas, iin: 636021, abbreviation: "AR", country: Country::UnitedStates, name: "Arkansas", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::California, iin: 636014, abbreviation: "CA", country: Country::UnitedStates, name: "California", time_zone: "America/Los_Angeles" },
+    JurisdictionData { jurisdiction: Jurisdiction::Colorado, iin: 636020, abbreviation: "CO", country: Country::UnitedStates, name: "Colorado", time_zone: "America/Denver" },
+    JurisdictionData { jurisdiction: Jurisdiction::Connecticut, iin: 636006, abbreviation: "CT", country: Country::UnitedStates, name: "Connecticut", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Delaware, iin: 636011, abbreviation: "DE", country: Country::UnitedStates, name: "Delaware", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::DistrictOfColumbia, iin: 636043, abbreviation: "DC", country: Country::UnitedStates, name: "District of Columbia", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Florida, iin: 636010, abbreviation: "FL", country: Country::UnitedStates, name: "Florida", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Georgia, iin: 636055, abbreviation: "GA", country: Country::UnitedStates, name: "Georgia", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Hawaii, iin: 636047, abbreviation: "HI", country: Country::UnitedStates, name: "Hawaii", time_zone: "Pacific/Honolulu" },
+    JurisdictionData { jurisdiction: Jurisdiction::Idaho, iin: 636050, abbreviation: "ID", country: Country::UnitedStates, name: "Idaho", time_zone: "America/Boise" },
+    JurisdictionData { jurisdiction: Jurisdiction::Illinois, iin: 636035, abbreviation: "IL", country: Country::UnitedStates, name: "Illinois", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Indiana, iin: 636037, abbreviation: "IN", country: Country::UnitedStates, name: "Indiana", time_zone: "America/Indiana/Indianapolis" },
+    JurisdictionData { jurisdiction: Jurisdiction::Iowa, iin: 636018, abbreviation: "IA", country: Country::UnitedStates, name: "Iowa", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Kansas, iin: 636022, abbreviation: "KS", country: Country::UnitedStates, name: "Kansas", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Kentucky, iin: 636046, abbreviation: "KY", country: Country::UnitedStates, name: "Kentucky", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Louisiana, iin: 636007, abbreviation: "LA", country: Country::UnitedStates, name: "Louisiana", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Maine, iin: 636041, abbreviation: "ME", country: Country::UnitedStates, name: "Maine", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Maryland, iin: 636003, abbreviation: "MD", country: Country::UnitedStates, name: "Maryland", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Massachusetts, iin: 636002, abbreviation: "MA", country: Country::UnitedStates, name: "Massachusetts", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Michigan, iin: 636032, abbreviation: "MI", country: Country::UnitedStates, name: "Michigan", time_zone: "America/Detroit" },
+    JurisdictionData { jurisdiction: Jurisdiction::Minnesota, iin: 636038, abbreviation: "MN", country: Country::UnitedStates, name: "Minnesota", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Mississippi, iin: 636051, abbreviation: "MS", country: Country::UnitedStates, name: "Mississippi", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Missouri, iin: 636030, abbreviation: "MO", country: Country::UnitedStates, name: "Missouri", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Montana, iin: 636008, abbreviation: "MT", country: Country::UnitedStates, name: "Montana", time_zone: "America/Denver" },
+    JurisdictionData { jurisdiction: Jurisdiction::Nebraska, iin: 636054, abbreviation: "NE", country: Country::UnitedStates, name: "Nebraska", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Nevada, iin: 636049, abbreviation: "NV", country: Country::UnitedStates, name: "Nevada", time_zone: "America/Los_Angeles" },
+    JurisdictionData { jurisdiction: Jurisdiction::NewHampshire, iin: 636039, abbreviation: "NH", country: Country::UnitedStates, name: "New Hampshire", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::NewJersey, iin: 636036, abbreviation: "NJ", country: Country::UnitedStates, name: "New Jersey", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::NewMexico, iin: 636009, abbreviation: "NM", country: Country::UnitedStates, name: "New Mexico", time_zone: "America/Denver" },
+    JurisdictionData { jurisdiction: Jurisdiction::NewYork, iin: 636001, abbreviation: "NY", country: Country::UnitedStates, name: "New York", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::NorthCarolina, iin: 636004, abbreviation: "NC", country: Country::UnitedStates, name: "North Carolina", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::NorthDakota, iin: 636034, abbreviation: "ND", country: Country::UnitedStates, name: "North Dakota", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Ohio, iin: 636023, abbreviation: "OH", country: Country::UnitedStates, name: "Ohio", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Oklahoma, iin: 636058, abbreviation: "OK", country: Country::UnitedStates, name: "Oklahoma", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Oregon, iin: 636029, abbreviation: "OR", country: Country::UnitedStates, name: "Oregon", time_zone: "America/Los_Angeles" },
+    JurisdictionData { jurisdiction: Jurisdiction::Pennsylvania, iin: 636025, abbreviation: "PA", country: Country::UnitedStates, name: "Pennsylvania", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::RhodeIsland, iin: 636052, abbreviation: "RI", country: Country::UnitedStates, name: "Rhode Island", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::SouthCarolina, iin: 636005, abbreviation: "SC", country: Country::UnitedStates, name: "South Carolina", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::SouthDakota, iin: 636042, abbreviation: "SD", country: Country::UnitedStates, name: "South Dakota", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Tennessee, iin: 636053, abbreviation: "TN", country: Country::UnitedStates, name: "Tennessee", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Texas, iin: 636015, abbreviation: "TX", country: Country::UnitedStates, name: "Texas", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Utah, iin: 636040, abbreviation: "UT", country: Country::UnitedStates, name: "Utah", time_zone: "America/Denver" },
+    JurisdictionData { jurisdiction: Jurisdiction::Vermont, iin: 636024, abbreviation: "VT", country: Country::UnitedStates, name: "Vermont", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Virginia, iin: 636000, abbreviation: "VA", country: Country::UnitedStates, name: "Virginia", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Washington, iin: 636045, abbreviation: "WA", country: Country::UnitedStates, name: "Washington", time_zone: "America/Los_Angeles" },
+    JurisdictionData { jurisdiction: Jurisdiction::WestVirginia, iin: 636061, abbreviation: "WV", country: Country::UnitedStates, name: "West Virginia", time_zone: "America/New_York" },
+    JurisdictionData { jurisdiction: Jurisdiction::Wisconsin, iin: 636031, abbreviation: "WI", country: Country::UnitedStates, name: "Wisconsin", time_zone: "America/Chicago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Wyoming, iin: 636060, abbreviation: "WY", country: Country::UnitedStates, name: "Wyoming", time_zone: "America/Denver" },
+    JurisdictionData { jurisdiction: Jurisdiction::AmericanSamoa, iin: 604427, abbreviation: "AS", country: Country::UnitedStates, name: "American Samoa", time_zone: "Pacific/Pago_Pago" },
+    JurisdictionData { jurisdiction: Jurisdiction::Guam, iin: 636019, abbreviation: "GU", country: Country::UnitedStates, name: "Guam", time_zone: "Pacific/Guam" },
+    JurisdictionData { jurisdiction: Jurisdiction::NorthernMarianaIslands, iin: 604430, abbreviation: "MP", country: Country::UnitedStates, name: "Northern Mariana Islands", time_zone: "Pacific/Saipan" },
+    JurisdictionData { jurisdiction: Jurisdiction::PuertoRico, iin: 604431, abbreviation: "PR", country: Country::UnitedStates, name: "Puerto Rico", time_zone: "America/Puerto_Rico" },
+    JurisdictionData { jurisdiction: Jurisdiction::UsVirginIslands, iin: 636062, abbreviation: "VI", country: Country::UnitedStates, name: "U.S. Virgin Islands", time_zone: "America/St_Thomas" },
+    JurisdictionData { jurisdiction: Jurisdiction::Alberta, iin: 604432, abbreviation: "AB", country: Country::Canada, name: "Alberta", time_zone: "America/Edmonton" },
+    JurisdictionData { jurisdiction: Jurisdiction::BritishColumbia, iin: 636028, abbreviation: "BC", country: Country::Canada, name: "British Columbia", time_zone: "America/Vancouver" },
+    JurisdictionData { jurisdiction: Jurisdiction::Manitoba, iin: 636048, abbreviation: "MB", country: Country::Canada, name: "Manitoba", time_zone: "America/Winnipeg" },
+    JurisdictionData { jurisdiction: Jurisdiction::NewBrunswick, iin: 636017, abbreviation: "NB", country: Country::Canada, name: "New Brunswick", time_zone: "America/Moncton" },
+    JurisdictionData { jurisdiction: Jurisdiction::NewfoundlandAndLabrador, iin: 636016, abbreviation: "NL", country: Country::Canada, name: "Newfoundland and Labrador", time_zone: "America/St_Johns" },
+    JurisdictionData { jurisdiction: Jurisdiction::NorthwestTerritories, iin: 604434, abbreviation: "NT", country: Country::Canada, name: "Northwest Territories", time_zone: "America/Yellowknife" },
+    JurisdictionData { jurisdiction: Jurisdiction::NovaScotia, iin: 636013, abbreviation: "NS", country: Country::Canada, name: "Nova Scotia", time_zone: "America/Halifax" },
+    JurisdictionData { jurisdiction: Jurisdiction::Nunavut, iin: 604433, abbreviation: "NU", country: Country::Canada, name: "Nunavut", time_zone: "America/Iqaluit" },
+    JurisdictionData { jurisdiction: Jurisdiction::Ontario, iin: 636012, abbreviation: "ON", country: Country::Canada, name: "Ontario", time_zone: "America/Toronto" },
+    JurisdictionData { jurisdiction: Jurisdiction::PrinceEdwardIsland, iin: 604426, abbreviation: "PE", country: Country::Canada, name: "Prince Edward Island", time_zone: "America/Halifax" },
+    JurisdictionData { jurisdiction: Jurisdiction::Quebec, iin: 604428, abbreviation: "QC", country: Country::Canada, name: "Quebec", time_zone: "America/Toronto" },
+    JurisdictionData { jurisdiction: Jurisdiction::Saskatchewan, iin: 636044, abbreviation: "SK", country: Country::Canada, name: "Saskatchewan", time_zone: "America/Regina" },
+    JurisdictionData { jurisdiction: Jurisdiction::Yukon, iin: 604429, abbreviation: "YT", country: Country::Canada, name: "Yukon", time_zone: "America/Whitehorse" },
+    JurisdictionData { jurisdiction: Jurisdiction::Coahuila, iin: 636056, abbreviation: "CU", country: Country::Mexico, name: "Coahuila", time_zone: "America/Monterrey" },
+    JurisdictionData { jurisdiction: Jurisdiction::Hidalgo, iin: 636057, abbreviation: "HI", country: Country::Mexico, name: "Hidalgo", time_zone: "America/Mexico_City" }
+];
+
+impl Jurisdiction {
+
+    fn data(&self) -> &'static JurisdictionData {
+        // The table is in declaration order, so the discriminant is the index.
+        &JURISDICTIONS[*self as usize]
+    }
+
+    /// Every known jurisdiction, in the order US, Canada, Mexico.
+    pub fn all() -> impl Iterator<Item = Jurisdiction> {
+        JURISDICTIONS.iter().map(|data| data.jurisdiction)
+    }
+
+    /// Looks up the jurisdiction from the issuer identification number in the header, None when the IIN is not
+    /// one this crate knows about.
+    pub fn from_iin(iin: u32) -> Option<Jurisdiction> {
+        JURISDICTIONS.iter().find(|data| data.iin == iin).map(|data| data.jurisdiction)
+    }
+
+    /// Looks up the jurisdiction from a DAJ code such as "CA". Mexican codes that clash with a US or Canadian code
+    /// resolve to the US or Canadian jurisdiction, use `from_code_in` with the DCG country to tell them apart.
+    pub fn from_code(code: &str) -> Option<Jurisdiction> {
+        let code = code.trim();
+
+        JURISDICTIONS.iter().find(|data| data.abbreviation == code).map(|data| data.jurisdiction)
+    }
+
+    pub fn from_code_in(code: &str, country: Country) -> Option<Jurisdiction> {
+        let code = code.trim();
+
+        JURISDICTIONS.iter()
+            .find(|data| data.abbreviation == code && data.country == country)
+            .map(|data| data.jurisdiction)
+    }
+
+    /// The AAMVA issuer identification number.
+    pub fn iin(&self) -> u32 {
+        self.data().iin
+    }
+
+    /// The postal abbreviation, as used by the DAJ element.
+    pub fn abbreviation(&self) -> &'static str {
+        self.data().abbreviation
+    }
+
+    pub fn country(&self) -> Country {
+        self.data().country
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.data().name
+    }
+
+    /// The IANA time zone of the jurisdiction's capital, jurisdictions spanning several zones still have one default.
+    pub fn time_zone(&self) -> &'static str {
+        self.data().time_zone
+    }
+
+    /// `time_zone` as a `chrono_tz::Tz`.
+    pub fn tz(&self) -> Tz {
+        self.time_zone().parse().unwrap_or(Tz::UTC)
+    }
+}
+
+impl fmt::Display for Jurisdiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jurisdiction::{Country, Jurisdiction};
+
+    #[test]
+    fn test_table_is_consistent() {
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(Jurisdiction::from_iin(jurisdiction.iin()), Some(jurisdiction));
+            assert_eq!(Jurisdiction::from_code_in(jurisdiction.abbreviation(), jurisdiction.country()), Some(jurisdiction));
+            assert_eq!(jurisdiction.tz().name(), jurisdiction.time_zone());
+        }
+    }
+
+    #[test]
+    fn test_lookups() {
+        assert_eq!(Jurisdiction::from_iin(636014), Some(Jurisdiction::California));
+        assert_eq!(Jurisdiction::from_iin(604428).map(|jurisdiction| jurisdiction.country()), Some(Country::Canada));
+        assert_eq!(Jurisdiction::from_iin(999999), None);
+        assert_eq!(Jurisdiction::from_code("QC "), Some(Jurisdiction::Quebec));
+        assert_eq!(Jurisdiction::from_code("HI"), Some(Jurisdiction::Hawaii));
+        assert_eq!(Jurisdiction::from_code_in("HI", Country::Mexico), Some(Jurisdiction::Hidalgo));
+        assert_eq!(Jurisdiction::PuertoRico.time_zone(), "America/Puerto_Rico");
+    }
+}
@@ -1,7 +1,31 @@
 pub mod error;
+pub mod encoding;
 pub mod header;
 pub mod elements;
+pub mod jurisdiction;
 pub mod documents;
+pub mod subfile;
+pub mod record;
+pub mod versions;
+pub mod dates;
+pub mod height;
+pub mod codes;
+pub mod weight;
+pub mod postal;
+pub mod name;
+pub mod compliance;
+pub mod privileges;
+pub mod clock;
+pub mod age;
+pub mod validity;
+pub mod diagnostics;
+pub mod options;
+
+#[cfg(test)]
+mod fixtures;
+
+#[cfg(test)]
+mod properties;
 
 
 
@@ -9,6 +33,7 @@ pub mod documents;
 mod tests {
 
     use crate::documents::{DriversLicense, Reader};
+    use crate::options::ParserOptions;
 
     #[test]
     fn test_asni() {
@@ -17,7 +42,7 @@ mod tests {
         let results = rxing::helpers::detect_multiple_in_file(file_name).expect("decodes");
         
         for result in results {
-            let (header, dl) = DriversLicense::new(result.getText().to_string()).unwrap();
+            let (header, dl) = DriversLicense::new(result.getText().to_string(), &ParserOptions::default()).unwrap();
             println!("{:#?}", header);
             println!("{:#?}", dl);
         }
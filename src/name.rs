@@ -0,0 +1,92 @@
+use crate::error::Error;
+
+/// Whether a name element was cut short to fit its declared length (DDE, DDF and DDG).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Truncation {
+    Truncated,
+    NotTruncated,
+    Unknown
+}
+
+impl Truncation {
+
+    /// Parses the T, N or U of a truncation element, `element_id` is only used to describe the error.
+    pub fn parse(element_id: &str, value: &str) -> Result<Truncation, Error> {
+        match value.trim() {
+            "T" => Ok(Truncation::Truncated),
+            "N" => Ok(Truncation::NotTruncated),
+            "U" => Ok(Truncation::Unknown),
+            _ => Err(Error::UnknownCode { element_id: element_id.to_string(), value: value.to_string() })
+        }
+    }
+}
+
+/// One part of the cardholder's name together with its truncation indicator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePart {
+    pub value: String,
+    pub truncation: Truncation
+}
+
+impl NamePart {
+
+    /// Pairs a name with its indicator, cards that predate the indicators report `Truncation::Unknown`.
+    pub fn new(value: Option<&str>, truncation: Option<Truncation>) -> Option<NamePart> {
+        value.map(|value| NamePart { value: value.to_string(), truncation: truncation.unwrap_or(Truncation::Unknown) })
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.truncation == Truncation::Truncated
+    }
+
+    /// Compares the part with a full name, ignoring case. A truncated part only holds the start of the name, so it
+    /// matches any name it is a prefix of.
+    pub fn matches(&self, name: &str) -> bool {
+        let value = self.value.to_uppercase();
+        let name = name.trim().to_uppercase();
+
+        match self.truncation {
+            Truncation::Truncated => name.starts_with(&value),
+            _ => name == value
+        }
+    }
+}
+
+/// The cardholder's name as DCS, DAC and DAD, each linked to its truncation indicator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Name {
+    pub family: Option<NamePart>,
+    pub first: Option<NamePart>,
+    pub middle: Option<NamePart>
+}
+
+impl Name {
+
+    pub fn is_truncated(&self) -> bool {
+        [&self.family, &self.first, &self.middle].iter().any(|part| part.as_ref().is_some_and(NamePart::is_truncated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::name::{NamePart, Truncation};
+
+    #[test]
+    fn test_truncation() {
+        assert_eq!(Truncation::parse("DDE", "T").unwrap(), Truncation::Truncated);
+        assert_eq!(Truncation::parse("DDE", "N").unwrap(), Truncation::NotTruncated);
+        assert!(Truncation::parse("DDE", "X").is_err());
+        assert_eq!(NamePart::new(Some("SMITH"), None).unwrap().truncation, Truncation::Unknown);
+    }
+
+    #[test]
+    fn test_matches() {
+        let truncated = NamePart { value: "WOLFESCHLEGEL".to_string(), truncation: Truncation::Truncated };
+        assert!(truncated.matches("Wolfeschlegelsteinhausen"));
+        assert!(!truncated.matches("Wolfe"));
+
+        let complete = NamePart { value: "SMITH".to_string(), truncation: Truncation::NotTruncated };
+        assert!(complete.matches("smith"));
+        assert!(!complete.matches("SMITHSON"));
+    }
+}
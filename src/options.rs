@@ -0,0 +1,187 @@
+use crate::error::Error;
+
+/// What the parser does when a card breaks one of the rules of the AAMVA standard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Accept the card and keep the value, without a diagnostic.
+    Ignore,
+    /// Accept the card and keep the value, reporting it as a warning.
+    Warn,
+    /// Report it with its usual severity. Values that break the character class or length of their element are left
+    /// out of the document, while codes outside the AAMVA tables are kept as `Other`, and a broken header or subfile
+    /// directory is recovered from where possible.
+    Report,
+    /// Fail the parse.
+    Reject
+}
+
+impl Policy {
+
+    /// Whether a value breaking the rule is still put on the document.
+    pub fn keeps_value(&self) -> bool {
+        matches!(self, Policy::Ignore | Policy::Warn)
+    }
+}
+
+/// Which occurrence of a repeated element is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateResolution {
+    FirstWins,
+    LastWins
+}
+
+/// How strictly a card is read, see `ParserOptions::strict` and `ParserOptions::lenient`. The default reports
+/// problems with the elements and rejects a malformed header. Options can be adjusted rule by rule, such as
+/// `ParserOptions::lenient().header_fields(Policy::Reject)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// Elements the AAMVA version of the card does not define, and AAMVA versions without an element dictionary,
+    /// which are read with the dictionary of the latest version.
+    pub unknown_elements: Policy,
+    /// Values longer or shorter than their element allows.
+    pub length_violations: Policy,
+    /// Every other problem with a value, such as characters outside its character class, impossible dates, codes
+    /// outside the AAMVA code tables or elements that disagree with each other.
+    pub invalid_values: Policy,
+    /// Header fields and subfile designators that cannot be read or do not point at their subfile.
+    pub header_fields: Policy,
+    /// Elements that appear more than once in a subfile.
+    pub duplicate_elements: Policy,
+    /// Which occurrence of a repeated element is put on the document, the others are still listed in its
+    /// `duplicate_elements`.
+    pub duplicate_resolution: DuplicateResolution,
+    /// Anything other than "@", LF, RS and CR before the header.
+    pub separators: Policy,
+    /// Whether the messages of diagnostics quote the values on the card. They are left out by default, as the values
+    /// are personal information that should not end up in logs.
+    pub include_values: bool
+}
+
+impl ParserOptions {
+
+    /// Rejects any card that breaks the standard.
+    pub fn strict() -> ParserOptions {
+        ParserOptions {
+            unknown_elements: Policy::Reject,
+            length_violations: Policy::Reject,
+            invalid_values: Policy::Reject,
+            header_fields: Policy::Reject,
+            duplicate_elements: Policy::Reject,
+            duplicate_resolution: DuplicateResolution::FirstWins,
+            separators: Policy::Reject,
+            include_values: false
+        }
+    }
+
+    /// Accepts anything that can be read, keeping every value and reporting the problems as warnings.
+    pub fn lenient() -> ParserOptions {
+        ParserOptions {
+            unknown_elements: Policy::Ignore,
+            length_violations: Policy::Warn,
+            invalid_values: Policy::Warn,
+            header_fields: Policy::Warn,
+            duplicate_elements: Policy::Warn,
+            duplicate_resolution: DuplicateResolution::FirstWins,
+            separators: Policy::Warn,
+            include_values: false
+        }
+    }
+
+    pub fn unknown_elements(mut self, policy: Policy) -> ParserOptions {
+        self.unknown_elements = policy;
+        self
+    }
+
+    pub fn length_violations(mut self, policy: Policy) -> ParserOptions {
+        self.length_violations = policy;
+        self
+    }
+
+    pub fn invalid_values(mut self, policy: Policy) -> ParserOptions {
+        self.invalid_values = policy;
+        self
+    }
+
+    pub fn header_fields(mut self, policy: Policy) -> ParserOptions {
+        self.header_fields = policy;
+        self
+    }
+
+    pub fn duplicate_elements(mut self, policy: Policy) -> ParserOptions {
+        self.duplicate_elements = policy;
+        self
+    }
+
+    pub fn duplicate_resolution(mut self, resolution: DuplicateResolution) -> ParserOptions {
+        self.duplicate_resolution = resolution;
+        self
+    }
+
+    pub fn separators(mut self, policy: Policy) -> ParserOptions {
+        self.separators = policy;
+        self
+    }
+
+    pub fn include_values(mut self, include_values: bool) -> ParserOptions {
+        self.include_values = include_values;
+        self
+    }
+
+    /// The policy for the rule the error breaks.
+    pub fn policy(&self, error: &Error) -> Policy {
+        match error {
+            Error::UnknownElement { .. } | Error::UnknownVersion { .. } => self.unknown_elements,
+            Error::InvalidStringLength { .. } => self.length_violations,
+            Error::DuplicateElement { .. } => self.duplicate_elements,
+            Error::UnexpectedSeparators { .. } => self.separators,
+            Error::NonCompliance
+            | Error::Formmating { .. }
+            | Error::InvalidFileType
+            | Error::HeaderTooShort { .. }
+            | Error::InvalidNumber { .. }
+            | Error::OutOfRange { .. }
+            | Error::InvalidSubfileType { .. }
+            | Error::SubfileOutOfBounds { .. }
+            | Error::SubfileTypeMismatch { .. }
+            | Error::SubfileNotFound { .. } => self.header_fields,
+            _ => self.invalid_values
+        }
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> ParserOptions {
+        ParserOptions {
+            unknown_elements: Policy::Report,
+            length_violations: Policy::Report,
+            invalid_values: Policy::Report,
+            header_fields: Policy::Reject,
+            duplicate_elements: Policy::Report,
+            duplicate_resolution: DuplicateResolution::FirstWins,
+            separators: Policy::Reject,
+            include_values: false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::options::{ParserOptions, Policy};
+
+    #[test]
+    fn test_policies() {
+        let unknown = Error::UnknownElement { element_id: "DXX".to_string(), aamva_version: 9 };
+        let length = Error::InvalidStringLength { element_id: "DAQ".to_string(), found: 30, expected: "at most 25".to_string() };
+
+        assert_eq!(ParserOptions::default().policy(&unknown), Policy::Report);
+        assert_eq!(ParserOptions::strict().policy(&length), Policy::Reject);
+        assert_eq!(ParserOptions::lenient().policy(&unknown), Policy::Ignore);
+        assert_eq!(ParserOptions::lenient().policy(&Error::InvalidFileType), Policy::Warn);
+
+        let custom = ParserOptions::strict().unknown_elements(Policy::Warn);
+        assert_eq!(custom.policy(&unknown), Policy::Warn);
+        assert!(custom.policy(&unknown).keeps_value());
+        assert_eq!(custom.policy(&length), Policy::Reject);
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// Postal code of the cardholder's address (DAK), normalised from the layouts found on cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostalCode {
+    /// A five digit ZIP code.
+    Zip5(String),
+    /// A ZIP code with its four digit extension.
+    ZipPlus4(String, String),
+    /// A Canadian postal code without its space, such as "K1A0B1".
+    Canadian(String),
+    /// Anything else, trimmed but otherwise kept as encoded.
+    Other(String)
+}
+
+impl PostalCode {
+
+    /// Reads "123450000  ", "12345-6789", "12345" and "K1A 0B1" alike, the "0000" extension that jurisdictions
+    /// write when the extension is not known is dropped. Returns `None` for a blank value.
+    pub fn parse(value: &str) -> Option<PostalCode> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let compact: String = trimmed.chars()
+            .filter(|character| *character != ' ' && *character != '-')
+            .collect::<String>()
+            .to_ascii_uppercase();
+
+        let digits = compact.bytes().all(|byte| byte.is_ascii_digit());
+
+        let postal_code = match compact.len() {
+            5 if digits => PostalCode::Zip5(compact),
+            9 if digits && compact.ends_with("0000") => PostalCode::Zip5(compact[..5].to_string()),
+            9 if digits => PostalCode::ZipPlus4(compact[..5].to_string(), compact[5..].to_string()),
+            6 if is_canadian(&compact) => PostalCode::Canadian(compact),
+            _ => PostalCode::Other(trimmed.to_string())
+        };
+
+        Some(postal_code)
+    }
+
+    /// The five digit ZIP code, if this is a US postal code.
+    pub fn zip5(&self) -> Option<&str> {
+        match self {
+            PostalCode::Zip5(zip) | PostalCode::ZipPlus4(zip, _) => Some(zip),
+            _ => None
+        }
+    }
+}
+
+// Canadian postal codes alternate letters and digits, starting with a letter.
+fn is_canadian(value: &str) -> bool {
+    value.bytes().enumerate().all(|(index, byte)| match index % 2 {
+        0 => byte.is_ascii_alphabetic(),
+        _ => byte.is_ascii_digit()
+    })
+}
+
+/// Formats the postal code in the style used on mail, such as "12345-6789" or "K1A 0B1".
+impl fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PostalCode::Zip5(zip) => f.write_str(zip),
+            PostalCode::ZipPlus4(zip, extension) => write!(f, "{zip}-{extension}"),
+            PostalCode::Canadian(code) => write!(f, "{} {}", &code[..3], &code[3..]),
+            PostalCode::Other(code) => f.write_str(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postal::PostalCode;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(PostalCode::parse("958180000  "), Some(PostalCode::Zip5("95818".to_string())));
+        assert_eq!(PostalCode::parse("12345-6789"), Some(PostalCode::ZipPlus4("12345".to_string(), "6789".to_string())));
+        assert_eq!(PostalCode::parse("123456789  "), Some(PostalCode::ZipPlus4("12345".to_string(), "6789".to_string())));
+        assert_eq!(PostalCode::parse("k1a 0b1    "), Some(PostalCode::Canadian("K1A0B1".to_string())));
+        assert_eq!(PostalCode::parse("SW1A 1AA"), Some(PostalCode::Other("SW1A 1AA".to_string())));
+        assert_eq!(PostalCode::parse("           "), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(PostalCode::parse("123456789").unwrap().to_string(), "12345-6789");
+        assert_eq!(PostalCode::parse("K1A0B1").unwrap().to_string(), "K1A 0B1");
+        assert_eq!(PostalCode::parse("958180000").unwrap().zip5(), Some("95818"));
+    }
+}
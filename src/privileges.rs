@@ -0,0 +1,268 @@
+use chrono::NaiveDate;
+
+use crate::codes::{Code, CodeList, Endorsement, Restriction, VehicleClass};
+use crate::documents::DriversLicense;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Permitted,
+    Denied,
+    /// The card does not hold enough information to decide.
+    Unknown
+}
+
+/// The answer to whether a class or endorsement may be used, with the reasons that led to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    pub decision: Decision,
+    pub reasons: Vec<String>
+}
+
+impl Evaluation {
+
+    fn new() -> Evaluation {
+        Evaluation { decision: Decision::Permitted, reasons: Vec::new() }
+    }
+
+    pub fn is_permitted(&self) -> bool {
+        self.decision == Decision::Permitted
+    }
+
+    fn deny(&mut self, reason: String) {
+        self.decision = Decision::Denied;
+        self.reasons.push(reason);
+    }
+
+    fn unknown(&mut self, reason: String) {
+        if self.decision == Decision::Permitted {
+            self.decision = Decision::Unknown;
+        }
+        self.reasons.push(reason);
+    }
+
+    fn note(&mut self, reason: String) {
+        self.reasons.push(reason);
+    }
+}
+
+/// The driving privileges of a licence on a given day, from the standard (DCM, DCN, DCO) and jurisdiction specific
+/// (DCA, DCD, DCB) codes and the expiration dates of the licence (DBA) and its HAZMAT endorsement (DDC). The
+/// jurisdiction codes are kept apart from the standard ones, as a jurisdiction class C is usually an ordinary licence
+/// rather than the commercial class C, and only ever match themselves.
+#[derive(Debug, Clone)]
+pub struct Privileges {
+    pub as_of: NaiveDate,
+    pub expiration_date: Option<NaiveDate>,
+    pub hazmat_expiration_date: Option<NaiveDate>,
+    /// The commercial classes of DCM, `None` when the card does not hold the element.
+    pub vehicle_classes: Option<Vec<VehicleClass>>,
+    pub jurisdiction_vehicle_classes: Vec<VehicleClass>,
+    pub endorsements: Vec<Endorsement>,
+    pub jurisdiction_endorsements: Vec<Endorsement>,
+    pub restrictions: Vec<Restriction>,
+    pub jurisdiction_restrictions: Vec<Restriction>
+}
+
+impl Privileges {
+
+    pub fn new(drivers_license: &DriversLicense, as_of: NaiveDate) -> Privileges {
+        Privileges {
+            as_of,
+            expiration_date: drivers_license.document_expiration_date,
+            hazmat_expiration_date: drivers_license.hazmat_endorsement_expiration,
+            vehicle_classes: drivers_license.standard_vehicle_classification.as_ref().map(|list| list.codes.clone()),
+            jurisdiction_vehicle_classes: codes(&drivers_license.jurisdiction_specific_vehicle_class),
+            endorsements: codes(&drivers_license.standard_endorsement_code),
+            jurisdiction_endorsements: codes(&drivers_license.jurisdiction_specific_endorsement_codes),
+            restrictions: codes(&drivers_license.standard_restriction_code),
+            jurisdiction_restrictions: codes(&drivers_license.jurisdiction_specific_restriction_codes)
+        }
+    }
+
+    /// Whether the holder may drive a vehicle of the given class. The standard classes of DCM are used when the card
+    /// holds them, where a class A licence covers class B and C vehicles and a class B licence covers class C
+    /// vehicles. Otherwise the jurisdiction classes of DCA are used, which only cover the same class.
+    pub fn vehicle_class(&self, class: &VehicleClass) -> Evaluation {
+        let mut evaluation = self.licence_validity();
+
+        match &self.vehicle_classes {
+            Some(held) => match held.iter().find(|held| covers(held, class)) {
+                Some(held) if held != class => evaluation.note(format!("class {} covers class {}", held.code(), class.code())),
+                Some(_) => {},
+                None => evaluation.deny(format!("class {} is not on the licence", class.code()))
+            },
+            None if self.jurisdiction_vehicle_classes.is_empty() => evaluation.unknown("the card holds no vehicle class".to_string()),
+            None if self.jurisdiction_vehicle_classes.contains(class) => {},
+            None => evaluation.deny(format!("class {} is not on the licence", class.code()))
+        }
+
+        self.note_restrictions(&mut evaluation);
+
+        evaluation
+    }
+
+    /// Whether the holder may use the given endorsement. An X endorsement covers both H and N, and holding both H
+    /// and N amounts to X. Jurisdiction endorsements of DCD only cover the same endorsement.
+    pub fn endorsement(&self, endorsement: &Endorsement) -> Evaluation {
+        let mut evaluation = self.licence_validity();
+
+        let holds = |endorsement: &Endorsement| self.endorsements.contains(endorsement);
+
+        let held = holds(endorsement) || self.jurisdiction_endorsements.contains(endorsement) || match endorsement {
+            Endorsement::HazardousMaterials | Endorsement::TankVehicle => holds(&Endorsement::TankVehicleWithHazardousMaterials),
+            Endorsement::TankVehicleWithHazardousMaterials => holds(&Endorsement::HazardousMaterials) && holds(&Endorsement::TankVehicle),
+            _ => false
+        };
+
+        if !held {
+            evaluation.deny(format!("endorsement {} is not on the licence", endorsement.code()));
+        }
+
+        if held && matches!(endorsement, Endorsement::HazardousMaterials | Endorsement::TankVehicleWithHazardousMaterials) {
+            match self.hazmat_expiration_date {
+                Some(expiration) if expiration < self.as_of => evaluation.deny(format!("HAZMAT endorsement expired {expiration}")),
+                Some(_) => {},
+                None => evaluation.unknown("the card holds no HAZMAT endorsement expiration date".to_string())
+            }
+        }
+
+        let blocking = match endorsement {
+            Endorsement::Passenger | Endorsement::SchoolBus => Some(Restriction::NoPassengers),
+            Endorsement::TankVehicle | Endorsement::TankVehicleWithHazardousMaterials => Some(Restriction::NoCargoInTankVehicle),
+            _ => None
+        };
+
+        if let Some(restriction) = blocking.filter(|restriction| self.restrictions.contains(restriction)) {
+            evaluation.deny(format!("restricted: {}", restriction.label()));
+        }
+
+        evaluation
+    }
+
+    // The licence itself is valid through the day it expires.
+    fn licence_validity(&self) -> Evaluation {
+        let mut evaluation = Evaluation::new();
+
+        match self.expiration_date {
+            Some(expiration) if expiration < self.as_of => evaluation.deny(format!("licence expired {expiration}")),
+            Some(_) => {},
+            None => evaluation.unknown("the card holds no expiration date".to_string())
+        }
+
+        evaluation
+    }
+
+    fn note_restrictions(&self, evaluation: &mut Evaluation) {
+        for restriction in &self.restrictions {
+            evaluation.note(format!("restricted: {}", restriction.label()));
+        }
+
+        for restriction in &self.jurisdiction_restrictions {
+            evaluation.note(format!("restricted by the jurisdiction: {}", restriction.code()));
+        }
+    }
+}
+
+fn covers(held: &VehicleClass, requested: &VehicleClass) -> bool {
+    match (held, requested) {
+        (VehicleClass::A, VehicleClass::B | VehicleClass::C) => true,
+        (VehicleClass::B, VehicleClass::C) => true,
+        _ => held == requested
+    }
+}
+
+fn codes<T: Clone>(list: &Option<CodeList<T>>) -> Vec<T> {
+    list.as_ref().map_or_else(Vec::new, |list| list.codes.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::codes::{Endorsement, VehicleClass};
+    use crate::documents::{DriversLicense, Reader};
+    use crate::fixtures::{build_file, CALIFORNIA_V09};
+    use crate::options::ParserOptions;
+    use crate::privileges::Decision;
+
+    fn commercial_license() -> DriversLicense {
+        let lines = ["DAQD1234562", "DBA08312030", "DDC03012025", "DCAA", "DCMA", "DCNHPT", "DCOBP"];
+
+        DriversLicense::new(build_file(CALIFORNIA_V09, &[("DL", &lines)]), &ParserOptions::default()).unwrap().1
+    }
+
+    fn license(lines: &[&str]) -> DriversLicense {
+        let lines: Vec<&str> = ["DAQD1234562", "DBA08312030"].iter().chain(lines).copied().collect();
+
+        DriversLicense::new(build_file(CALIFORNIA_V09, &[("DL", &lines)]), &ParserOptions::default()).unwrap().1
+    }
+
+    #[test]
+    fn test_vehicle_classes() {
+        let privileges = commercial_license().privileges(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+
+        let evaluation = privileges.vehicle_class(&VehicleClass::B);
+        assert_eq!(evaluation.decision, Decision::Permitted);
+        assert!(evaluation.reasons.contains(&"class A covers class B".to_string()));
+        assert!(evaluation.reasons.contains(&"restricted: Corrective lenses required".to_string()));
+
+        assert_eq!(privileges.vehicle_class(&VehicleClass::M).decision, Decision::Denied);
+
+        let expired = commercial_license().privileges(NaiveDate::from_ymd_opt(2030, 9, 1).unwrap());
+        assert_eq!(expired.vehicle_class(&VehicleClass::A).reasons[0], "licence expired 2030-08-31");
+    }
+
+    #[test]
+    fn test_jurisdiction_classes_are_not_commercial() {
+        let as_of = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+
+        let privileges = license(&["DCAC", "DCMNONE"]).privileges(as_of);
+        assert_eq!(privileges.jurisdiction_vehicle_classes, vec![VehicleClass::Other("C".to_string())]);
+        assert_eq!(privileges.vehicle_class(&VehicleClass::C).decision, Decision::Denied);
+
+        let privileges = license(&["DCAD"]).privileges(as_of);
+        assert!(privileges.vehicle_class(&VehicleClass::Other("D".to_string())).is_permitted());
+        assert_eq!(privileges.vehicle_class(&VehicleClass::Other("C".to_string())).decision, Decision::Denied);
+
+        let privileges = license(&["DCAA"]).privileges(as_of);
+        assert_eq!(privileges.vehicle_class(&VehicleClass::A).decision, Decision::Denied);
+        assert_eq!(privileges.vehicle_class(&VehicleClass::Other("B".to_string())).decision, Decision::Denied);
+
+        let privileges = license(&[]).privileges(as_of);
+        let evaluation = privileges.vehicle_class(&VehicleClass::B);
+        assert_eq!(evaluation.decision, Decision::Unknown);
+        assert_eq!(evaluation.reasons, vec!["the card holds no vehicle class"]);
+    }
+
+    #[test]
+    fn test_jurisdiction_codes_are_kept_apart() {
+        let privileges = license(&["DCAC", "DCDH", "DCBB", "DDC03012030"]).privileges(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        assert_eq!(privileges.endorsements, vec![]);
+        assert_eq!(privileges.endorsement(&Endorsement::HazardousMaterials).decision, Decision::Denied);
+        assert!(privileges.endorsement(&Endorsement::Other("H".to_string())).is_permitted());
+        assert_eq!(privileges.restrictions, vec![]);
+
+        let evaluation = privileges.vehicle_class(&VehicleClass::Other("C".to_string()));
+        assert!(evaluation.is_permitted());
+        assert_eq!(evaluation.reasons, vec!["restricted by the jurisdiction: B"]);
+    }
+
+    #[test]
+    fn test_endorsements() {
+        let privileges = commercial_license().privileges(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        assert!(privileges.endorsement(&Endorsement::HazardousMaterials).is_permitted());
+        assert_eq!(privileges.endorsement(&Endorsement::Passenger).decision, Decision::Denied);
+        assert_eq!(privileges.endorsement(&Endorsement::SchoolBus).decision, Decision::Denied);
+
+        let privileges = license(&["DCMA", "DCNHN", "DDC03012030"]).privileges(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        assert!(privileges.endorsement(&Endorsement::TankVehicleWithHazardousMaterials).is_permitted());
+
+        let privileges = license(&["DCMA", "DCNH", "DDC03012030"]).privileges(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        assert_eq!(privileges.endorsement(&Endorsement::TankVehicleWithHazardousMaterials).decision, Decision::Denied);
+
+        let privileges = commercial_license().privileges(NaiveDate::from_ymd_opt(2025, 3, 2).unwrap());
+        let evaluation = privileges.endorsement(&Endorsement::HazardousMaterials);
+        assert_eq!(evaluation.decision, Decision::Denied);
+        assert_eq!(evaluation.reasons, vec!["HAZMAT endorsement expired 2025-03-01"]);
+    }
+}
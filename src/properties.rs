@@ -0,0 +1,115 @@
+// Property tests for the public entry points, which take untrusted scanner input and must return an error rather
+// than panic on anything they are given.
+
+use proptest::prelude::*;
+
+use crate::documents::{DriversLicense, IdentificationCard, Reader};
+use crate::fixtures::{build_file, CALIFORNIA_V09};
+use crate::header::Header;
+use crate::options::ParserOptions;
+
+fn all_options() -> [ParserOptions; 3] {
+    [ParserOptions::default(), ParserOptions::strict(), ParserOptions::lenient()]
+}
+
+// Lines that look like AAMVA elements.
+fn element_line() -> impl Strategy<Value = String> {
+    "D[A-D][A-Z][ -~]{0,45}"
+}
+
+// The same with values drawn from Latin-1, which can look like mojibake and be rewritten by the encoding repair.
+fn latin1_element_line() -> impl Strategy<Value = String> {
+    "D[A-D][A-Z][ -~\u{a0}-\u{ff}]{0,45}"
+}
+
+// A header with its fields replaced by anything from the characters that appear in real headers.
+fn header_like() -> impl Strategy<Value = String> {
+    "\u{1e}\r(ANSI |AAMVA|ANS)?[0-9A-Z ]{0,60}"
+}
+
+// A file with a correct subfile directory whose text is then cut short or has a character replaced.
+fn damaged_file() -> impl Strategy<Value = String> {
+    (prop::collection::vec(latin1_element_line(), 0..12), "DL|ID|EN|ZC", any::<prop::sample::Index>(), any::<char>(), any::<bool>())
+        .prop_map(|(lines, subfile_type, index, replacement, truncate)| {
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let file: Vec<char> = build_file(CALIFORNIA_V09, &[(&subfile_type, &lines)]).chars().collect();
+            let at = index.index(file.len());
+
+            match truncate {
+                true => file[..at].iter().collect(),
+                false => file.iter().enumerate().map(|(position, character)| if position == at { replacement } else { *character }).collect()
+            }
+        })
+}
+
+fn read_everything(file: &str) {
+    let _ = DriversLicense::new(file.to_string(), &ParserOptions::default());
+    let _ = IdentificationCard::new(file.to_string(), &ParserOptions::default());
+
+    for options in all_options() {
+        if let Ok(parsed) = DriversLicense::read(file.to_string(), &options) {
+            for span in parsed.diagnostics.iter().filter_map(|diagnostic| diagnostic.span.clone()) {
+                assert!(parsed.decoded.get(span).is_some());
+            }
+        }
+
+        let _ = IdentificationCard::read_bytes(file.as_bytes(), &options);
+    }
+}
+
+proptest! {
+    #[test]
+    fn header_never_panics(header in any::<String>()) {
+        let _ = Header::from(header);
+    }
+
+    #[test]
+    fn header_like_input_never_panics(header in header_like()) {
+        let _ = Header::from(header);
+    }
+
+    #[test]
+    fn documents_never_panic(file in any::<String>()) {
+        read_everything(&file);
+        read_everything(&format!("@\n{file}"));
+    }
+
+    #[test]
+    fn documents_from_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..400)) {
+        let _ = DriversLicense::from_bytes(&bytes, &ParserOptions::default());
+
+        for options in all_options() {
+            let _ = DriversLicense::read_bytes(&bytes, &options);
+        }
+    }
+
+    #[test]
+    fn damaged_files_never_panic(file in damaged_file()) {
+        read_everything(&file);
+    }
+
+    #[test]
+    fn well_formed_files_are_read(lines in prop::collection::vec(element_line(), 1..20)) {
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let file = build_file(CALIFORNIA_V09, &[("DL", &lines)]);
+
+        for options in [ParserOptions::default(), ParserOptions::lenient()] {
+            prop_assert!(DriversLicense::read(file.clone(), &options).is_ok());
+        }
+    }
+}
+
+#[test]
+fn inputs_that_used_to_panic() {
+    assert!(Header::from("\u{1e}\rAN".to_string()).is_err());
+    assert!(Header::from(String::new()).is_err());
+    assert!(DriversLicense::new(String::new(), &ParserOptions::default()).is_err());
+    assert!(DriversLicense::new("@".to_string(), &ParserOptions::default()).is_err());
+
+    let file = build_file(CALIFORNIA_V09, &[("DL", &["DA", "ÉÉ", "DAQé1"])]);
+    assert!(DriversLicense::new(file.clone(), &ParserOptions::default()).is_ok());
+    assert!(IdentificationCard::new(file, &ParserOptions::default()).is_ok());
+
+    let file = build_file(CALIFORNIA_V09, &[("DL", &[])]);
+    assert!(DriversLicense::new(file, &ParserOptions::default()).is_ok());
+}
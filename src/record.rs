@@ -0,0 +1,141 @@
+use crate::documents::ParseContext;
+use crate::elements::Element;
+use crate::error::Error;
+use crate::header::{Header, HeaderRef, SubfileDesignator, SubfileType};
+use crate::options::Policy;
+use crate::subfile::{Subfile, SubfileRef};
+
+/// A parsed file that borrows from its input, the only allocations are the subfile directory and the line index.
+/// `Record::element` reads elements without allocating, whereas `Reader` builds the owned `DriversLicense` and
+/// `IdentificationCard` from a record, which allocates for each value.
+#[derive(Debug)]
+pub struct Record<'a> {
+    pub header: HeaderRef<'a>,
+    pub subfiles: Vec<SubfileRef<'a>>
+}
+
+impl<'a> Record<'a> {
+
+    pub fn parse(file: &'a str) -> Result<Record<'a>, Error> {
+        Record::parse_with(file, &mut ParseContext::default())
+    }
+
+    /// Parses the file under the header and separator policies of the context's parser options. Problems that are
+    /// recovered from are reported to the context, a document subfile the directory does not point at is searched
+    /// for by its type code.
+    pub fn parse_with(file: &'a str, context: &mut ParseContext) -> Result<Record<'a>, Error> {
+        if let Some(fields) = file.strip_prefix("@\n\x1e\r") {
+            return Record::parse_fields(file, fields, 0, context);
+        }
+
+        if context.options.separators == Policy::Reject {
+            return match file.strip_prefix("@\n") {
+                Some(header) => HeaderRef::parse(header).and(Err(Error::NonCompliance)),
+                None => Err(Error::NonCompliance)
+            };
+        }
+
+        let at = file.find('@').ok_or(Error::NonCompliance)?;
+        let fields = file[at + 1..].trim_start_matches(['\n', '\r', '\x1c', '\x1d', '\x1e', ' ']);
+
+        context.recover(Error::UnexpectedSeparators { position: file.len() - fields.len() })?;
+
+        Record::parse_fields(file, fields, at, context)
+    }
+
+    // Parses the header fields of a file whose compliance indicator is at `at`, subfile offsets count from it.
+    fn parse_fields(file: &'a str, fields: &'a str, at: usize, context: &mut ParseContext) -> Result<Record<'a>, Error> {
+        let header = match HeaderRef::parse_fields(fields) {
+            Ok(header) => header,
+            Err(error) => {
+                context.recover(error)?;
+
+                let header = HeaderRef::recover(fields);
+                let subfiles = [SubfileType::DriversLicense, SubfileType::IdentificationCard, SubfileType::EnhancedDriversLicense]
+                    .into_iter()
+                    .filter_map(|subfile_type| SubfileRef::find(file, subfile_type))
+                    .min_by_key(|subfile| subfile.designator.offset())
+                    .into_iter()
+                    .collect();
+
+                return Ok(Record { header, subfiles });
+            }
+        };
+
+        let shift = file[..at].chars().count();
+        let mut subfiles = Vec::new();
+
+        for designator in header.subfile_designators() {
+            let offset = u16::try_from(designator.offset() as usize + shift).unwrap_or(u16::MAX);
+            let shifted = SubfileDesignator::new(designator.subfile_type(), offset, designator.length());
+
+            match SubfileRef::parse(file, shifted) {
+                Ok(subfile) => subfiles.push(subfile),
+                Err(error) => {
+                    context.recover(error)?;
+                    subfiles.extend(SubfileRef::find(file, designator.subfile_type()));
+                }
+            }
+        }
+
+        Ok(Record { header, subfiles })
+    }
+
+    /// The first subfile that is not jurisdiction specific, holding the DL, ID or EN elements.
+    pub fn document(&self) -> Option<&SubfileRef<'a>> {
+        self.subfiles.iter().find(|subfile| !subfile.is_jurisdiction_specific())
+    }
+
+    /// Returns the raw value of an element in the document subfile, without removing any padding.
+    pub fn get(&self, id: &str) -> Option<&'a str> {
+        self.document().and_then(|subfile| subfile.get(id))
+    }
+
+    /// The value of an element in the document subfile with its padding removed, borrowed from the input. Values
+    /// are returned as encoded, without validation or the renaming of older AAMVA versions.
+    pub fn element<E: Element>(&self) -> Option<&'a str> {
+        self.get(E::data().id).map(|value| value.trim_end_matches(' '))
+    }
+
+    pub fn to_owned(&self) -> (Header, Vec<Subfile>) {
+        (
+            self.header.to_owned(),
+            self.subfiles.iter().map(|subfile| subfile.to_owned()).collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::documents::{DriversLicense, ParseContext, Reader};
+    use crate::elements::{AddressPostalCode, CustomerFamilyName, Under21Until};
+    use crate::fixtures::{build_file, CALIFORNIA_V09, DRIVERS_LICENSE};
+    use crate::record::Record;
+
+    #[test]
+    fn test_borrowed_record() {
+        let file = build_file(CALIFORNIA_V09, &[("DL", DRIVERS_LICENSE), ("ZC", &["ZCAVALUE"])]);
+        let record = Record::parse(&file).unwrap();
+
+        assert_eq!(record.header.issuer_identification_number(), 636014);
+        assert_eq!(record.get("DAQ"), Some("D1234562"));
+        assert_eq!(record.get("DAK"), Some("958180000  "));
+        assert_eq!(record.subfiles[1].get("ZCA"), Some("VALUE"));
+
+        let value = record.get("DCS").unwrap();
+        assert!(file.as_bytes().as_ptr_range().contains(&value.as_ptr()));
+
+        let value = record.element::<AddressPostalCode>().unwrap();
+        assert_eq!(value, "958180000");
+        assert!(file.as_bytes().as_ptr_range().contains(&value.as_ptr()));
+        assert_eq!(record.element::<CustomerFamilyName>(), Some("SAMPLE"));
+        assert_eq!(record.element::<Under21Until>(), None);
+
+        let drivers_license = DriversLicense::from_lines(&record.document().unwrap().lines, &mut ParseContext::default());
+        assert_eq!(drivers_license.customer_family_name.as_deref(), Some("SAMPLE"));
+
+        let (header, subfiles) = record.to_owned();
+        assert_eq!(header.issuer_identification_number(), 636014);
+        assert_eq!(subfiles[0].lines, DRIVERS_LICENSE);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::error::Error;
+use crate::header::{SubfileDesignator, SubfileType};
+use crate::jurisdiction::Jurisdiction;
+
+#[derive(Debug)]
+pub struct Subfile {
+    pub designator: SubfileDesignator,
+    pub lines: Vec<String>
+}
+
+impl Subfile {
+
+    pub fn subfile_type(&self) -> SubfileType {
+        self.designator.subfile_type()
+    }
+
+    /// Jurisdiction specific subfiles have a subfile type beginning with 'Z', such as ZV or ZC.
+    pub fn is_jurisdiction_specific(&self) -> bool {
+        self.subfile_type().is_jurisdiction_specific()
+    }
+}
+
+/// Borrowed form of `Subfile`, each line is a slice of the input it was parsed from.
+#[derive(Debug)]
+pub struct SubfileRef<'a> {
+    pub designator: SubfileDesignator,
+    pub lines: Vec<&'a str>
+}
+
+impl<'a> SubfileRef<'a> {
+
+    /// Slices the subfile described by `designator` out of the whole `file`, checking that it begins with its
+    /// subfile type code and ends with the segment terminator.
+    pub fn parse(file: &'a str, designator: SubfileDesignator) -> Result<SubfileRef<'a>, Error> {
+        let subfile_type = designator.subfile_type().to_string();
+        let offset = designator.offset() as usize;
+        let length = designator.length() as usize;
+
+        let contents = char_slice(file, offset, length)
+            .ok_or_else(|| Error::SubfileOutOfBounds {
+                subfile_type: subfile_type.clone(),
+                offset,
+                length,
+                file_length: file.chars().count()
+            })?;
+
+        let body = match contents.strip_prefix(&*subfile_type) {
+            Some(body) => body,
+            None => return Err(Error::SubfileTypeMismatch {
+                offset,
+                expected: subfile_type,
+                found: contents.chars().take(2).collect()
+            })
+        };
+
+        let body = match body.strip_suffix('\r') {
+            Some(body) => body,
+            None => return Err(Error::Formmating {
+                violation: format!("Segment terminator not found at the end of subfile '{subfile_type}'")
+            })
+        };
+
+        Ok(SubfileRef { designator, lines: split_lines(body) })
+    }
+
+    /// Looks for a subfile by its type code, for cards whose subfile directory does not point at it. The type code
+    /// must be followed by an element ID, "D" and two letters for AAMVA subfiles or the code itself for jurisdiction
+    /// specific ones, so that the subfile directory is not mistaken for the subfile. The subfile runs up to the next
+    /// segment terminator.
+    pub fn find(file: &'a str, subfile_type: SubfileType) -> Option<SubfileRef<'a>> {
+        let code = subfile_type.to_string();
+        let element_start = match subfile_type {
+            SubfileType::Jurisdiction(_) => code.as_bytes(),
+            _ => b"D"
+        };
+
+        let (start, _) = file.match_indices(&*code).find(|(index, _)| {
+            let element = file.as_bytes().get(index + 2..index + 5).unwrap_or_default();
+
+            element.starts_with(element_start) && element.iter().all(u8::is_ascii_uppercase)
+        })?;
+
+        let rest = &file[start + 2..];
+        let body = rest.split('\r').next().unwrap_or(rest);
+
+        let offset = u16::try_from(file[..start].chars().count()).unwrap_or(u16::MAX);
+        let length = u16::try_from(body.chars().count() + 3).unwrap_or(u16::MAX);
+        let designator = SubfileDesignator::new(subfile_type, offset, length);
+
+        Some(SubfileRef { designator, lines: split_lines(body) })
+    }
+
+    pub fn subfile_type(&self) -> SubfileType {
+        self.designator.subfile_type()
+    }
+
+    pub fn is_jurisdiction_specific(&self) -> bool {
+        self.subfile_type().is_jurisdiction_specific()
+    }
+
+    /// Returns the raw value of the first element with the given ID, without removing any padding.
+    pub fn get(&self, id: &str) -> Option<&'a str> {
+        self.elements().find(|(element_id, _)| *element_id == id).map(|(_, value)| value)
+    }
+
+    /// Element IDs and raw values in the order they appear in the subfile, lines too short to hold an ID are skipped.
+    pub fn elements(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.lines.iter().filter_map(|line| line.split_at_checked(3))
+    }
+
+    pub fn to_owned(&self) -> Subfile {
+        Subfile {
+            designator: self.designator,
+            lines: self.lines.iter().map(|line| line.to_string()).collect()
+        }
+    }
+}
+
+fn split_lines(body: &str) -> Vec<&str> {
+    body.split('\n')
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+// Offsets and lengths count bytes of the Latin-1 encoded file, which is one character per byte once decoded.
+fn char_slice(file: &str, offset: usize, length: usize) -> Option<&str> {
+    let mut boundaries = file.char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(file.len()));
+
+    let start = boundaries.nth(offset)?;
+    let end = match length {
+        0 => start,
+        _ => boundaries.nth(length - 1)?
+    };
+
+    file.get(start..end)
+}
+
+/// A jurisdiction specific ("Z") subfile, whose elements are defined by the issuing jurisdiction rather than AAMVA.
+#[derive(Debug)]
+pub struct JurisdictionSubfile {
+    pub designator: SubfileDesignator,
+    pub issuer_identification_number: u32,
+    elements: Vec<(String, String)>
+}
+
+impl JurisdictionSubfile {
+
+    pub fn from(subfile: Subfile, issuer_identification_number: u32) -> JurisdictionSubfile {
+        let elements = subfile.lines
+            .into_iter()
+            .map(|line| match line.split_at_checked(3) {
+                Some((id, value)) => (id.to_string(), value.to_string()),
+                None => (line, String::new())
+            })
+            .collect();
+
+        JurisdictionSubfile {
+            designator: subfile.designator,
+            issuer_identification_number,
+            elements
+        }
+    }
+
+    pub fn jurisdiction(&self) -> Option<Jurisdiction> {
+        Jurisdiction::from_iin(self.issuer_identification_number)
+    }
+
+    /// Returns the raw value of the first element with the given ID, such as "ZCA".
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.elements
+            .iter()
+            .find(|(element_id, _)| element_id == id)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Element IDs and raw values in the order they appear in the subfile.
+    pub fn elements(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.elements.iter().map(|(id, value)| (id.as_str(), value.as_str()))
+    }
+}
@@ -0,0 +1,159 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::clock::Clock;
+use crate::jurisdiction::Jurisdiction;
+
+/// Why the validity of a card could not be decided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidityUnknown {
+    NoExpirationDate,
+    NoIssueDate
+}
+
+/// Whether a card has expired, a card is valid through the end of its expiration day in the time zone of the
+/// issuing jurisdiction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiry {
+    Valid { expires_at: DateTime<Tz> },
+    /// The card has expired, but less than the grace period ago.
+    InGracePeriod { expired_at: DateTime<Tz>, grace_ends_at: DateTime<Tz> },
+    Expired { expired_at: DateTime<Tz> },
+    Unknown(ValidityUnknown)
+}
+
+impl Expiry {
+
+    /// `None` when the card carries no expiration date, a card in its grace period is not expired.
+    pub fn is_expired(&self) -> Option<bool> {
+        match self {
+            Expiry::Valid { .. } | Expiry::InGracePeriod { .. } => Some(false),
+            Expiry::Expired { .. } => Some(true),
+            Expiry::Unknown(_) => None
+        }
+    }
+}
+
+/// Whether a card has been issued yet, a card is valid from the start of its issue day in the time zone of the
+/// issuing jurisdiction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Issue {
+    Valid { valid_from: DateTime<Tz> },
+    NotYetValid { valid_from: DateTime<Tz> },
+    Unknown(ValidityUnknown)
+}
+
+impl Issue {
+
+    pub fn is_not_yet_valid(&self) -> Option<bool> {
+        match self {
+            Issue::Valid { .. } => Some(false),
+            Issue::NotYetValid { .. } => Some(true),
+            Issue::Unknown(_) => None
+        }
+    }
+}
+
+/// The issue and expiration dates of a card in the time zone of its issuing jurisdiction, UTC when the
+/// jurisdiction is not known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Validity {
+    pub issue_date: Option<NaiveDate>,
+    pub expiration_date: Option<NaiveDate>,
+    pub time_zone: Tz
+}
+
+impl Validity {
+
+    pub fn new(issue_date: Option<NaiveDate>, expiration_date: Option<NaiveDate>, jurisdiction: Option<Jurisdiction>) -> Validity {
+        let time_zone = jurisdiction.map_or(Tz::UTC, |jurisdiction| jurisdiction.tz());
+
+        Validity { issue_date, expiration_date, time_zone }
+    }
+
+    pub fn expiry(&self, clock: &impl Clock, grace_period: Option<Duration>) -> Expiry {
+        let Some(expiration_date) = self.expiration_date else {
+            return Expiry::Unknown(ValidityUnknown::NoExpirationDate);
+        };
+
+        let now = clock.now();
+        let expired_at = self.start_of_day(expiration_date.succ_opt().unwrap_or(expiration_date));
+        let grace_ends_at = expired_at + grace_period.unwrap_or_else(Duration::zero);
+
+        if now < expired_at {
+            Expiry::Valid { expires_at: expired_at }
+        } else if now < grace_ends_at {
+            Expiry::InGracePeriod { expired_at, grace_ends_at }
+        } else {
+            Expiry::Expired { expired_at }
+        }
+    }
+
+    /// Days from today in the issuing jurisdiction to the expiration date, zero on the last valid day and negative
+    /// once the card has expired.
+    pub fn days_until_expiry(&self, clock: &impl Clock) -> Result<i64, ValidityUnknown> {
+        let expiration_date = self.expiration_date.ok_or(ValidityUnknown::NoExpirationDate)?;
+        let today = clock.now().with_timezone(&self.time_zone).date_naive();
+
+        Ok((expiration_date - today).num_days())
+    }
+
+    pub fn issue(&self, clock: &impl Clock) -> Issue {
+        let Some(issue_date) = self.issue_date else {
+            return Issue::Unknown(ValidityUnknown::NoIssueDate);
+        };
+
+        let valid_from = self.start_of_day(issue_date);
+
+        match clock.now() < valid_from {
+            true => Issue::NotYetValid { valid_from },
+            false => Issue::Valid { valid_from }
+        }
+    }
+
+    // Midnight local time, or the first instant after it on days where a DST change skips midnight.
+    fn start_of_day(&self, date: NaiveDate) -> DateTime<Tz> {
+        let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+
+        self.time_zone.from_local_datetime(&midnight).earliest()
+            .or_else(|| self.time_zone.from_local_datetime(&(midnight + Duration::hours(1))).earliest())
+            .unwrap_or_else(|| Utc.from_utc_datetime(&midnight).with_timezone(&self.time_zone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    use crate::clock::FixedClock;
+    use crate::jurisdiction::Jurisdiction;
+    use crate::validity::{Expiry, Issue, Validity, ValidityUnknown};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_expiry_follows_the_jurisdiction_time_zone() {
+        let validity = Validity::new(None, Some(date(2030, 8, 31)), Some(Jurisdiction::California));
+
+        // 06:00 UTC on September 1 is still August 31 in Los Angeles.
+        let clock = FixedClock(Utc.with_ymd_and_hms(2030, 9, 1, 6, 0, 0).unwrap());
+        assert!(matches!(validity.expiry(&clock, None), Expiry::Valid { .. }));
+        assert_eq!(validity.days_until_expiry(&clock), Ok(0));
+
+        let clock = FixedClock(Utc.with_ymd_and_hms(2030, 9, 1, 8, 0, 0).unwrap());
+        assert_eq!(validity.expiry(&clock, None).is_expired(), Some(true));
+        assert_eq!(validity.days_until_expiry(&clock), Ok(-1));
+        assert!(matches!(validity.expiry(&clock, Some(Duration::days(30))), Expiry::InGracePeriod { .. }));
+    }
+
+    #[test]
+    fn test_issue() {
+        let validity = Validity::new(Some(date(2020, 8, 31)), None, Some(Jurisdiction::Ontario));
+
+        assert!(matches!(validity.issue(&FixedClock::on(date(2020, 8, 30))), Issue::NotYetValid { .. }));
+        assert_eq!(validity.issue(&FixedClock::on(date(2020, 8, 31))).is_not_yet_valid(), Some(false));
+        assert_eq!(validity.expiry(&FixedClock::on(date(2020, 8, 31)), None), Expiry::Unknown(ValidityUnknown::NoExpirationDate));
+    }
+}
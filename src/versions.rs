@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+/// The newest AAMVA version this crate has an element dictionary for.
+pub const LATEST_VERSION: u8 = 10;
+
+/// Rewrites the elements of older AAMVA versions into the element IDs used since version 04, so they land in the
+/// same fields of `DriversLicense` and `IdentificationCard`.
+///
+/// Elements whose ID was reused with a different meaning, such as DBG which was a medical code in version 01 and
+/// is an alias given name today, are dropped rather than filling the wrong field.
+pub fn normalize_lines<'a>(lines: &[&'a str], aamva_version: u8) -> Vec<Cow<'a, str>> {
+    let mut normalized: Vec<Cow<'a, str>> = Vec::with_capacity(lines.len());
+
+    for line in lines {
+        let (id, value) = match line.split_at_checked(3) {
+            Some(split) => split,
+            None => {
+                normalized.push(Cow::Borrowed(line));
+                continue;
+            }
+        };
+
+        match (aamva_version, id) {
+            (1, "DAB") => normalized.push(rename("DCS", value)),
+            (1, "DAE") => normalized.push(rename("DCU", value)),
+            (1, "DAR") => normalized.push(rename("DCA", value)),
+            (1, "DAS") => normalized.push(rename("DCB", value)),
+            (1, "DAT") => normalized.push(rename("DCD", value)),
+            (1, "DBO") => normalized.push(rename("DBN", value)),
+            (1, "DBP") => normalized.push(rename("DBG", value)),
+            (1, "DBR") => normalized.push(rename("DBS", value)),
+            (1, "DAV") => normalized.push(Cow::Owned(format!("DAU{} CM", value.trim()))),
+            (1, "DBH") => normalized.push(rename("DDK", if value.trim() == "Y" { "1" } else { "0" })),
+            (1, "DAA") => {
+                let names: Vec<&str> = value.split(',').map(|name| name.trim()).collect();
+
+                // The separate name elements take precedence over the full name.
+                let slots = [("DCS", "DAB"), ("DAC", "DAC"), ("DAD", "DAD"), ("DCU", "DAE")];
+
+                for ((modern_id, version_01_id), name) in slots.iter().zip(names) {
+                    if !name.is_empty() && !lines.iter().any(|line| line.starts_with(version_01_id)) {
+                        normalized.push(rename(modern_id, name));
+                    }
+                }
+            },
+            (1, "DBG" | "DBN" | "DBS") => {},
+            (2 | 3, "DCT") => {
+                let separator = if value.contains(',') { ',' } else { ' ' };
+
+                match value.trim().split_once(separator) {
+                    Some((first, middle)) => {
+                        normalized.push(rename("DAC", first.trim()));
+                        normalized.push(rename("DAD", middle.trim()));
+                    },
+                    None => normalized.push(rename("DAC", value))
+                }
+            },
+            _ => normalized.push(Cow::Borrowed(line))
+        }
+    }
+
+    normalized
+}
+
+fn rename<'a>(id: &str, value: &str) -> Cow<'a, str> {
+    Cow::Owned(format!("{id}{value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::versions::normalize_lines;
+
+    #[test]
+    fn test_version_01_names() {
+        let lines = normalize_lines(&["DAAPUBLIC,JOHN,Q", "DARD", "DBGMED", "DBPJOHNNY", "DBHY"], 1);
+        assert_eq!(lines, vec!["DCSPUBLIC", "DACJOHN", "DADQ", "DCAD", "DBGJOHNNY", "DDK1"]);
+
+        let lines = normalize_lines(&["DAAPUBLIC,JOHN,Q", "DACJACK"], 1);
+        assert_eq!(lines, vec!["DCSPUBLIC", "DADQ", "DACJACK"]);
+    }
+
+    #[test]
+    fn test_version_03_given_names() {
+        let lines = normalize_lines(&["DCSPUBLIC", "DCTJOHN,QUINCY", "DAQ123"], 3);
+        assert_eq!(lines, vec!["DCSPUBLIC", "DACJOHN", "DADQUINCY", "DAQ123"]);
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+const POUNDS_PER_KILOGRAM: f64 = 2.204_622_6;
+
+// Lower bounds of the weight range codes 0 through 9 of DCE, in pounds and in kilograms.
+const RANGE_POUNDS: [u16; 10] = [0, 71, 101, 131, 161, 191, 221, 251, 281, 321];
+const RANGE_KILOGRAMS: [u16; 10] = [0, 32, 46, 60, 71, 87, 101, 114, 128, 146];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightUnit {
+    Pounds,
+    Kilograms
+}
+
+/// One of the ten weight ranges of DCE, used by jurisdictions that do not encode an exact weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightRange(u8);
+
+impl WeightRange {
+
+    pub fn new(code: u8) -> Option<WeightRange> {
+        (code <= 9).then_some(WeightRange(code))
+    }
+
+    /// The range an exact weight falls into.
+    pub fn containing(value: u16, unit: WeightUnit) -> WeightRange {
+        let bounds = match unit {
+            WeightUnit::Pounds => &RANGE_POUNDS,
+            WeightUnit::Kilograms => &RANGE_KILOGRAMS
+        };
+
+        WeightRange(bounds.iter().rposition(|&lower| value >= lower).unwrap_or(0) as u8)
+    }
+
+    pub fn code(&self) -> u8 {
+        self.0
+    }
+
+    /// The inclusive bounds of the range in pounds, the last range has no upper bound.
+    pub fn pounds(&self) -> (u16, Option<u16>) {
+        Self::bounds(&RANGE_POUNDS, self.0)
+    }
+
+    /// The inclusive bounds of the range in kilograms, the last range has no upper bound.
+    pub fn kilograms(&self) -> (u16, Option<u16>) {
+        Self::bounds(&RANGE_KILOGRAMS, self.0)
+    }
+
+    fn bounds(table: &[u16; 10], code: u8) -> (u16, Option<u16>) {
+        let code = code as usize;
+
+        (table[code], table.get(code + 1).map(|upper| upper - 1))
+    }
+}
+
+/// Weight of the cardholder, built from whichever of DAW (pounds), DAX (kilograms) and DCE (range) the card holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weight {
+    exact: Option<(u16, WeightUnit)>,
+    range: Option<WeightRange>
+}
+
+impl Weight {
+
+    pub fn exact(value: u16, unit: WeightUnit) -> Weight {
+        Weight { exact: Some((value, unit)), range: None }
+    }
+
+    pub fn from_range(range: WeightRange) -> Weight {
+        Weight { exact: None, range: Some(range) }
+    }
+
+    /// Combines the elements found on a card, DAW is preferred over DAX when both are present.
+    pub fn from_elements(pounds: Option<u16>, kilograms: Option<u16>, range: Option<WeightRange>) -> Option<Weight> {
+        let exact = pounds.map(|pounds| (pounds, WeightUnit::Pounds))
+            .or(kilograms.map(|kilograms| (kilograms, WeightUnit::Kilograms)));
+
+        (exact.is_some() || range.is_some()).then_some(Weight { exact, range })
+    }
+
+    /// The exact weight in the unit it was encoded with.
+    pub fn value(&self) -> Option<(u16, WeightUnit)> {
+        self.exact
+    }
+
+    pub fn to_pounds(&self) -> Option<u16> {
+        self.exact.map(|(value, unit)| match unit {
+            WeightUnit::Pounds => value,
+            WeightUnit::Kilograms => (f64::from(value) * POUNDS_PER_KILOGRAM).round() as u16
+        })
+    }
+
+    pub fn to_kilograms(&self) -> Option<u16> {
+        self.exact.map(|(value, unit)| match unit {
+            WeightUnit::Pounds => (f64::from(value) / POUNDS_PER_KILOGRAM).round() as u16,
+            WeightUnit::Kilograms => value
+        })
+    }
+
+    /// The range encoded in DCE, or else the range the exact weight falls into.
+    pub fn range(&self) -> Option<WeightRange> {
+        self.range.or(self.exact.map(|(value, unit)| WeightRange::containing(value, unit)))
+    }
+
+    /// False when the card holds both an exact weight and a range code that does not contain it.
+    pub fn is_consistent(&self) -> bool {
+        match (self.exact, self.range) {
+            (Some((value, unit)), Some(range)) => WeightRange::containing(value, unit) == range,
+            _ => true
+        }
+    }
+}
+
+impl fmt::Display for Weight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.exact, self.range()) {
+            (Some((value, WeightUnit::Pounds)), _) => write!(f, "{value} lb"),
+            (Some((value, WeightUnit::Kilograms)), _) => write!(f, "{value} kg"),
+            (None, Some(range)) => match range.pounds() {
+                (lower, Some(upper)) => write!(f, "{lower}-{upper} lb"),
+                (lower, None) => write!(f, "{lower}+ lb")
+            },
+            (None, None) => Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::weight::{Weight, WeightRange, WeightUnit};
+
+    #[test]
+    fn test_conversion() {
+        let weight = Weight::exact(150, WeightUnit::Pounds);
+        assert_eq!(weight.to_kilograms(), Some(68));
+        assert_eq!(weight.range(), WeightRange::new(3));
+
+        let weight = Weight::exact(68, WeightUnit::Kilograms);
+        assert_eq!(weight.to_pounds(), Some(150));
+        assert_eq!(weight.to_string(), "68 kg");
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(WeightRange::new(0).unwrap().pounds(), (0, Some(70)));
+        assert_eq!(WeightRange::new(4).unwrap().kilograms(), (71, Some(86)));
+        assert_eq!(WeightRange::new(9).unwrap().pounds(), (321, None));
+        assert_eq!(WeightRange::new(10), None);
+
+        let weight = Weight::from_range(WeightRange::new(2).unwrap());
+        assert_eq!(weight.to_pounds(), None);
+        assert_eq!(weight.to_string(), "101-130 lb");
+    }
+
+    #[test]
+    fn test_consistency() {
+        assert!(Weight::from_elements(Some(150), None, WeightRange::new(3)).unwrap().is_consistent());
+        assert!(!Weight::from_elements(Some(150), None, WeightRange::new(7)).unwrap().is_consistent());
+        assert_eq!(Weight::from_elements(None, None, None), None);
+    }
+}